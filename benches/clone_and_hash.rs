@@ -0,0 +1,66 @@
+//! Compares the cost of cloning and hashing `Email` and `ArcEmail`,
+//! against a plain `String`.
+//!
+//! Run with `cargo bench --bench clone_and_hash`.
+
+use ::serde_email::ArcEmail;
+use ::serde_email::Email;
+use ::std::collections::hash_map::DefaultHasher;
+use ::std::hash::Hash;
+use ::std::hash::Hasher;
+use ::std::hint::black_box;
+use ::std::time::Duration;
+use ::std::time::Instant;
+
+const ITERATIONS: u32 = 1_000_000;
+const RAW_EMAIL: &str = "john.doe+notifications@mail.example.com";
+
+fn main() {
+    let string = RAW_EMAIL.to_string();
+    let email = Email::from_str(RAW_EMAIL).unwrap();
+    let arc_email = ArcEmail::from_str(RAW_EMAIL).unwrap();
+
+    println!("clone");
+    report(
+        "String",
+        time(|| drop(black_box(black_box(&string).clone()))),
+    );
+    report("Email", time(|| drop(black_box(black_box(&email).clone()))));
+    report(
+        "ArcEmail",
+        time(|| drop(black_box(black_box(&arc_email).clone()))),
+    );
+
+    println!("hash");
+    report("String", time(|| hash(black_box(&string))));
+    report("Email", time(|| hash(black_box(&email))));
+    report("ArcEmail", time(|| hash(black_box(&arc_email))));
+}
+
+fn time<F>(mut f: F) -> Duration
+where
+    F: FnMut(),
+{
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+
+    start.elapsed()
+}
+
+fn hash<T>(value: &T)
+where
+    T: Hash,
+{
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    black_box(hasher.finish());
+}
+
+fn report(name: &str, elapsed: Duration) {
+    println!(
+        "  {name:<10} {:>8.2} ns/iter",
+        elapsed.as_nanos() as f64 / f64::from(ITERATIONS)
+    );
+}
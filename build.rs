@@ -0,0 +1,108 @@
+use ::std::env;
+use ::std::fmt::Write;
+use ::std::fs;
+use ::std::path::PathBuf;
+
+/// Set this to the path of a `public_suffix_list.dat` file,
+/// to build with it in place of the snapshot within the crate.
+///
+/// Build scripts run in the directory of the crate,
+/// so a relative path is resolved against it and not the project being built.
+const PUBLIC_SUFFIX_LIST_ENV: &str = "SERDE_EMAIL_PUBLIC_SUFFIX_LIST";
+const PUBLIC_SUFFIX_LIST_SNAPSHOT: &str = "data/public_suffix_list.dat";
+const DISPOSABLE_DOMAINS: &str = "data/disposable_domains.txt";
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    if env::var_os("CARGO_FEATURE_PUBLIC_SUFFIX").is_some() {
+        build_public_suffix_rules();
+    }
+
+    if env::var_os("CARGO_FEATURE_DISPOSABLE").is_some() {
+        build_disposable_domains();
+    }
+}
+
+/// Turns the Public Suffix List into sorted arrays of rules,
+/// which are searched when looking up a domain.
+fn build_public_suffix_rules() {
+    println!("cargo:rerun-if-env-changed={PUBLIC_SUFFIX_LIST_ENV}");
+    let path = env::var_os(PUBLIC_SUFFIX_LIST_ENV)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(PUBLIC_SUFFIX_LIST_SNAPSHOT));
+    println!("cargo:rerun-if-changed={}", path.display());
+
+    let list = fs::read_to_string(&path).unwrap_or_else(|err| {
+        panic!(
+            "failed to read the public suffix list at {}, {err}",
+            path.display()
+        )
+    });
+
+    let mut rules = Vec::new();
+    let mut wildcard_rules = Vec::new();
+    let mut exception_rules = Vec::new();
+
+    for line in list.lines() {
+        let Some(rule) = line.split_whitespace().next() else {
+            continue;
+        };
+        if rule.starts_with("//") {
+            continue;
+        }
+
+        let rule = rule.to_lowercase();
+        if let Some(rule) = rule.strip_prefix("*.") {
+            wildcard_rules.push(rule.to_string());
+        } else if let Some(rule) = rule.strip_prefix('!') {
+            exception_rules.push(rule.to_string());
+        } else {
+            rules.push(rule);
+        }
+    }
+
+    let mut code = String::new();
+    write_rules(&mut code, "RULES", rules);
+    write_rules(&mut code, "WILDCARD_RULES", wildcard_rules);
+    write_rules(&mut code, "EXCEPTION_RULES", exception_rules);
+
+    write_out_file("public_suffix_rules.rs", code);
+}
+
+/// Turns the list of disposable domains into a sorted array.
+fn build_disposable_domains() {
+    println!("cargo:rerun-if-changed={DISPOSABLE_DOMAINS}");
+
+    let list = fs::read_to_string(DISPOSABLE_DOMAINS).unwrap_or_else(|err| {
+        panic!("failed to read the disposable domains at {DISPOSABLE_DOMAINS}, {err}")
+    });
+
+    let domains = list
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(str::to_lowercase)
+        .collect();
+
+    let mut code = String::new();
+    write_rules(&mut code, "DISPOSABLE_DOMAINS", domains);
+    write_out_file("disposable_domains.rs", code);
+}
+
+fn write_out_file(name: &str, code: String) {
+    let out_dir = PathBuf::from(env::var_os("OUT_DIR").expect("OUT_DIR should be set by cargo"));
+    fs::write(out_dir.join(name), code)
+        .unwrap_or_else(|err| panic!("failed to write {name}, {err}"));
+}
+
+fn write_rules(code: &mut String, name: &str, mut rules: Vec<String>) {
+    rules.sort();
+    rules.dedup();
+
+    writeln!(code, "pub(crate) static {name}: &[&str] = &[").unwrap();
+    for rule in rules {
+        writeln!(code, "    {rule:?},").unwrap();
+    }
+    writeln!(code, "];").unwrap();
+}
@@ -0,0 +1,93 @@
+//!
+//! Macros for the `serde-email` crate.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use ::serde_email::Email;
+//! use ::serde_email_macros::email;
+//!
+//! let email: Email = email!("ops@example.com");
+//! assert_eq!(email, "ops@example.com");
+//! ```
+//!
+
+use ::proc_macro::TokenStream;
+use ::proc_macro2::TokenStream as TokenStream2;
+use ::quote::quote;
+use ::serde_email::Email;
+use ::syn::LitStr;
+
+/// Creates an `Email` from a string literal, validated at compile time.
+///
+/// The same rules as `is_valid_email` are used.
+/// An invalid email is a compile error, saying why it is invalid.
+///
+/// This can be used to build `static` and `const` emails.
+///
+/// ```rust
+/// use ::serde_email::Email;
+/// use ::serde_email_macros::email;
+///
+/// static NOREPLY: Email = email!("noreply@example.com");
+/// ```
+///
+/// ```compile_fail
+/// use ::serde_email_macros::email;
+///
+/// let email = email!("not an email");
+/// ```
+#[proc_macro]
+pub fn email(input: TokenStream) -> TokenStream {
+    expand_email(input.into()).into()
+}
+
+fn expand_email(input: TokenStream2) -> TokenStream2 {
+    let literal = match ::syn::parse2::<LitStr>(input) {
+        Ok(literal) => literal,
+        Err(err) => return err.to_compile_error(),
+    };
+
+    match Email::from_string(literal.value()) {
+        Ok(email) => {
+            let raw_email = email.as_str();
+
+            quote! {
+                ::serde_email::Email::from_static_unchecked(#raw_email)
+            }
+        }
+        Err(err) => ::syn::Error::new(literal.span(), err).to_compile_error(),
+    }
+}
+
+#[cfg(test)]
+mod test_expand_email {
+    use super::*;
+
+    #[test]
+    fn it_should_expand_valid_emails() {
+        let expanded = expand_email(quote! { "ops@example.com" });
+
+        let expected = quote! {
+            ::serde_email::Email::from_static_unchecked("ops@example.com")
+        };
+        assert_eq!(expanded.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn it_should_error_on_invalid_emails() {
+        let expanded = expand_email(quote! { "ops.example.com" }).to_string();
+
+        assert!(expanded.contains("compile_error"));
+        assert!(expanded.contains(
+            "invalid email address, was given 'ops.example.com', missing '@' between the local part and domain"
+        ));
+    }
+
+    #[test]
+    fn it_should_error_on_non_string_literals() {
+        let expanded = expand_email(quote! { 123 }).to_string();
+
+        assert!(expanded.contains("compile_error"));
+    }
+}
@@ -0,0 +1,342 @@
+use ::alloc::string::String;
+use ::alloc::string::ToString;
+use ::alloc::vec::Vec;
+use ::core::convert::AsRef;
+
+use crate::parse_mailbox::parse_display_name;
+use crate::parse_mailbox::skip_cfws;
+use crate::AddressListEntry;
+use crate::Email;
+use crate::EmailError;
+use crate::Mailbox;
+use crate::ValidationPolicy;
+
+/// A list of addresses, as found in the `To`, `Cc`, and `Bcc` headers,
+/// or in a column of a CSV file.
+/// i.e. `a@x.com, "B, Jr" <b@y.com>, Team: c@z.com, d@z.com;`.
+///
+/// Each entry is parsed on its own. An invalid entry has its own `EmailError`,
+/// and does not stop the rest of the list from being parsed.
+///
+/// Commas inside quotes, comments, and angle brackets do not split entries.
+/// Empty entries are skipped. Outside of a group a `;` is treated like a `,`,
+/// as some mail clients separate addresses that way.
+///
+/// ```rust
+/// use ::serde_email::AddressList;
+///
+/// let list = AddressList::parse(r#"a@x.com, "B, Jr" <b@y.com>, nope, Team: c@z.com;"#);
+///
+/// assert_eq!(list.emails().count(), 3);
+/// assert_eq!(list.errors().count(), 1);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct AddressList {
+    entries: Vec<AddressListEntry>,
+}
+
+impl AddressList {
+    /// Parses the list of addresses given.
+    pub fn parse<S>(raw: S) -> Self
+    where
+        S: AsRef<str>,
+    {
+        Self::parse_with(raw, ValidationPolicy::new())
+    }
+
+    /// Parses the list of addresses given,
+    /// validating each email using the rules of the policy.
+    pub fn parse_with<S, P>(raw: S, policy: P) -> Self
+    where
+        S: AsRef<str>,
+        P: Into<ValidationPolicy>,
+    {
+        Self {
+            entries: parse_address_list(raw.as_ref(), &policy.into()),
+        }
+    }
+
+    pub fn entries(&self) -> &[AddressListEntry] {
+        &self.entries
+    }
+
+    /// Returns every mailbox in the list, including the members of groups.
+    pub fn mailboxes(&self) -> impl Iterator<Item = &Result<Mailbox, EmailError>> {
+        self.entries.iter().flat_map(AddressListEntry::mailboxes)
+    }
+
+    /// Returns every valid mailbox in the list, including the members of groups.
+    pub fn valid_mailboxes(&self) -> impl Iterator<Item = &Mailbox> {
+        self.mailboxes().filter_map(|mailbox| mailbox.as_ref().ok())
+    }
+
+    /// Returns the email of every valid mailbox in the list.
+    pub fn emails(&self) -> impl Iterator<Item = &Email> {
+        self.valid_mailboxes().map(Mailbox::email)
+    }
+
+    /// Returns the errors for every invalid mailbox in the list.
+    pub fn errors(&self) -> impl Iterator<Item = &EmailError> {
+        self.mailboxes()
+            .filter_map(|mailbox| mailbox.as_ref().err())
+    }
+
+    /// Returns true if every mailbox in the list is valid.
+    pub fn is_valid(&self) -> bool {
+        self.errors().next().is_none()
+    }
+}
+
+struct Group {
+    display_name: String,
+    members: Vec<Result<Mailbox, EmailError>>,
+}
+
+fn parse_address_list(raw: &str, policy: &ValidationPolicy) -> Vec<AddressListEntry> {
+    let mut entries = Vec::new();
+    let mut group: Option<Group> = None;
+    let mut entry_start = 0;
+    let mut is_quoted = false;
+    let mut is_angled = false;
+    let mut is_bracketed = false;
+    let mut comment_depth = 0;
+    let mut chars = raw.char_indices();
+
+    while let Some((position, character)) = chars.next() {
+        match character {
+            '\\' if is_quoted || comment_depth > 0 => {
+                chars.next();
+            }
+            '"' if comment_depth == 0 => is_quoted = !is_quoted,
+            _ if is_quoted => {}
+            '(' => comment_depth += 1,
+            ')' if comment_depth > 0 => comment_depth -= 1,
+            _ if comment_depth > 0 => {}
+            '<' => is_angled = true,
+            '>' => is_angled = false,
+            _ if is_angled => {}
+            '[' => is_bracketed = true,
+            ']' => is_bracketed = false,
+            _ if is_bracketed => {}
+            ':' if group.is_none() => {
+                let raw_name = &raw[entry_start..position];
+                let display_name = parse_display_name(raw_name, raw_name.len())
+                    .ok()
+                    .flatten()
+                    .unwrap_or_else(|| raw_name.trim().to_string());
+
+                group = Some(Group {
+                    display_name,
+                    members: Vec::new(),
+                });
+                entry_start = position + 1;
+            }
+            ',' | ';' => {
+                let mailbox = parse_entry(&raw[entry_start..position], policy);
+                entry_start = position + 1;
+
+                match &mut group {
+                    Some(group) => group.members.extend(mailbox),
+                    None => entries.extend(mailbox.map(AddressListEntry::Mailbox)),
+                }
+
+                if character == ';' {
+                    if let Some(group) = group.take() {
+                        entries.push(AddressListEntry::Group {
+                            display_name: group.display_name,
+                            members: group.members,
+                        });
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mailbox = parse_entry(&raw[entry_start..], policy);
+    match group {
+        Some(mut group) => {
+            group.members.extend(mailbox);
+            entries.push(AddressListEntry::Group {
+                display_name: group.display_name,
+                members: group.members,
+            });
+        }
+        None => entries.extend(mailbox.map(AddressListEntry::Mailbox)),
+    }
+
+    entries
+}
+
+/// Parses a single mailbox from the list.
+/// Returns None if the entry is empty, or only whitespace and comments.
+fn parse_entry(raw: &str, policy: &ValidationPolicy) -> Option<Result<Mailbox, EmailError>> {
+    if let Ok(position) = skip_cfws(raw, 0) {
+        if position == raw.len() {
+            return None;
+        }
+    }
+
+    Some(Mailbox::from_str_with(raw.trim(), policy))
+}
+
+#[cfg(test)]
+mod test_parse {
+    use super::*;
+    use crate::EmailErrorKind;
+
+    fn mailbox(raw: &str) -> Result<Mailbox, EmailError> {
+        Ok(Mailbox::from_str(raw).unwrap())
+    }
+
+    #[test]
+    fn it_should_parse_a_single_address() {
+        let list = AddressList::parse("a@x.com");
+
+        assert_eq!(
+            list.entries(),
+            &[AddressListEntry::Mailbox(mailbox("a@x.com"))]
+        );
+    }
+
+    #[test]
+    fn it_should_parse_comma_separated_addresses() {
+        let list = AddressList::parse("a@x.com, b@y.com,c@z.com");
+
+        assert_eq!(
+            list.emails().map(Email::as_str).collect::<Vec<_>>(),
+            vec!["a@x.com", "b@y.com", "c@z.com"]
+        );
+    }
+
+    #[test]
+    fn it_should_not_split_on_quoted_commas() {
+        let list = AddressList::parse(r#""B, Jr" <b@y.com>, a@x.com"#);
+
+        assert_eq!(
+            list.entries(),
+            &[
+                AddressListEntry::Mailbox(mailbox(r#""B, Jr" <b@y.com>"#)),
+                AddressListEntry::Mailbox(mailbox("a@x.com")),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_not_split_on_commas_in_comments() {
+        let list = AddressList::parse("a@x.com (Smith, Jane), b@y.com");
+
+        assert_eq!(list.emails().count(), 2);
+        assert!(list.is_valid());
+    }
+
+    #[test]
+    fn it_should_not_start_groups_on_colons_in_domain_literals() {
+        let list = AddressList::parse("a@[IPv6:2001:db8::1], Team: b@y.com;");
+
+        assert_eq!(
+            list.entries(),
+            &[
+                AddressListEntry::Mailbox(mailbox("a@[IPv6:2001:db8::1]")),
+                AddressListEntry::Group {
+                    display_name: "Team".to_string(),
+                    members: vec![mailbox("b@y.com")],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_parse_groups() {
+        let list = AddressList::parse("a@x.com, Team: c@z.com, d@z.com;, e@z.com");
+
+        assert_eq!(
+            list.entries(),
+            &[
+                AddressListEntry::Mailbox(mailbox("a@x.com")),
+                AddressListEntry::Group {
+                    display_name: "Team".to_string(),
+                    members: vec![mailbox("c@z.com"), mailbox("d@z.com")],
+                },
+                AddressListEntry::Mailbox(mailbox("e@z.com")),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_parse_empty_groups() {
+        let list = AddressList::parse("undisclosed-recipients:;");
+
+        assert_eq!(
+            list.entries(),
+            &[AddressListEntry::Group {
+                display_name: "undisclosed-recipients".to_string(),
+                members: vec![],
+            }]
+        );
+    }
+
+    #[test]
+    fn it_should_parse_unterminated_groups() {
+        let list = AddressList::parse(r#""The Team": c@z.com, d@z.com"#);
+
+        assert_eq!(
+            list.entries(),
+            &[AddressListEntry::Group {
+                display_name: "The Team".to_string(),
+                members: vec![mailbox("c@z.com"), mailbox("d@z.com")],
+            }]
+        );
+    }
+
+    #[test]
+    fn it_should_return_errors_per_entry() {
+        let list = AddressList::parse("a@x.com, donkeys, b@y.com");
+
+        assert_eq!(list.emails().count(), 2);
+        assert_eq!(
+            list.errors().map(EmailError::kind).collect::<Vec<_>>(),
+            vec![&EmailErrorKind::MissingSeparator]
+        );
+        assert!(!list.is_valid());
+    }
+
+    #[test]
+    fn it_should_return_errors_within_groups() {
+        let list = AddressList::parse("Team: c@z.com, nope;");
+
+        assert_eq!(list.emails().count(), 1);
+        assert_eq!(list.errors().count(), 1);
+    }
+
+    #[test]
+    fn it_should_skip_empty_entries() {
+        let list = AddressList::parse(" , a@x.com,, (nobody) ,b@y.com, ");
+
+        assert_eq!(list.emails().count(), 2);
+        assert!(list.is_valid());
+    }
+
+    #[test]
+    fn it_should_treat_semicolons_outside_groups_as_separators() {
+        let list = AddressList::parse("a@x.com; b@y.com");
+
+        assert_eq!(list.emails().count(), 2);
+    }
+
+    #[test]
+    fn it_should_parse_an_empty_list() {
+        let list = AddressList::parse("");
+
+        assert!(list.entries().is_empty());
+    }
+
+    #[test]
+    fn it_should_validate_with_the_policy() {
+        let policy = ValidationPolicy::new().require_tld(true);
+        let list = AddressList::parse_with("a@x.com, b@localhost", policy);
+
+        assert_eq!(list.emails().count(), 1);
+        assert_eq!(list.errors().count(), 1);
+    }
+}
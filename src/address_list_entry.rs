@@ -0,0 +1,31 @@
+use ::alloc::string::String;
+use ::alloc::vec::Vec;
+use ::core::slice::from_ref;
+
+use crate::EmailError;
+use crate::Mailbox;
+
+/// A single entry within an `AddressList`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AddressListEntry {
+    /// A mailbox on its own, i.e. `"Jane Doe" <jane@example.com>`.
+    Mailbox(Result<Mailbox, EmailError>),
+
+    /// A named group of mailboxes, i.e. `Team: a@example.com, b@example.com;`.
+    /// Groups can be empty.
+    Group {
+        display_name: String,
+        members: Vec<Result<Mailbox, EmailError>>,
+    },
+}
+
+impl AddressListEntry {
+    /// Returns the mailboxes of this entry.
+    /// This is the mailbox itself, or the members of a group.
+    pub fn mailboxes(&self) -> &[Result<Mailbox, EmailError>] {
+        match self {
+            AddressListEntry::Mailbox(mailbox) => from_ref(mailbox),
+            AddressListEntry::Group { members, .. } => members,
+        }
+    }
+}
@@ -0,0 +1,501 @@
+use ::alloc::string::String;
+use ::alloc::string::ToString;
+use ::alloc::sync::Arc;
+use ::core::fmt::Display;
+use ::core::fmt::Formatter;
+use ::core::fmt::Result as FmtResult;
+use ::core::str::FromStr;
+
+use crate::Domain;
+use crate::Email;
+use crate::EmailError;
+use crate::EmailRef;
+use crate::MailtoUri;
+use crate::ProviderRules;
+use crate::ValidationPolicy;
+
+#[cfg(feature = "serde")]
+mod serde_support;
+
+#[cfg(feature = "sea-orm")]
+mod sea_orm_support;
+
+/// A validated email, which is cheap to clone.
+///
+/// The text of the email is held in an `Arc<str>`,
+/// so cloning only increments a reference count rather than copying the text.
+/// This is for emails which are cloned many times, such as when sending
+/// the same address in lots of messages.
+///
+/// It has the same methods as `Email`, and converts to and from one.
+///
+/// ```rust
+/// use ::serde_email::ArcEmail;
+/// use ::serde_email::Email;
+///
+/// let email = ArcEmail::from(Email::from_str("john@example.com").unwrap());
+/// let clone = email.clone();
+///
+/// assert_eq!(clone.domain(), "example.com");
+/// assert_eq!(Email::from(clone), email);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ArcEmail {
+    raw_email: Arc<str>,
+    at_index: usize,
+}
+
+impl ArcEmail {
+    /// Creates a new ArcEmail, from the `String` given.
+    ///
+    /// If the given string doesn't look like a valid email,
+    /// then this will return an EmailError describing why.
+    pub fn from_string(raw_email: String) -> Result<Self, EmailError> {
+        Email::from_string(raw_email).map(Self::from)
+    }
+
+    /// Creates a new ArcEmail, from the `String` given,
+    /// validating it using the rules of the policy.
+    pub fn from_string_with<P>(raw_email: String, policy: P) -> Result<Self, EmailError>
+    where
+        P: Into<ValidationPolicy>,
+    {
+        Email::from_string_with(raw_email, policy).map(Self::from)
+    }
+
+    /// Creates a new ArcEmail, from the `str` given.
+    ///
+    /// If the given string doesn't look like a valid email,
+    /// then this will return an EmailError describing why.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str<S>(raw_email: S) -> Result<Self, EmailError>
+    where
+        S: AsRef<str>,
+    {
+        EmailRef::from_str(raw_email.as_ref()).map(Self::from)
+    }
+
+    /// Creates a new ArcEmail, from the `str` given,
+    /// validating it using the rules of the policy.
+    pub fn from_str_with<S, P>(raw_email: S, policy: P) -> Result<Self, EmailError>
+    where
+        S: AsRef<str>,
+        P: Into<ValidationPolicy>,
+    {
+        EmailRef::from_str_with(raw_email.as_ref(), policy).map(Self::from)
+    }
+
+    /// Returns an `EmailRef` borrowing this email.
+    pub fn as_email_ref(&self) -> EmailRef<'_> {
+        EmailRef::from_valid_parts(&self.raw_email, self.at_index)
+    }
+
+    /// Returns an `Email` holding a copy of this email.
+    fn to_email(&self) -> Email {
+        Email::from(self.as_email_ref())
+    }
+
+    /// Returns a new ArcEmail, where the whole email has been lowercased.
+    /// See `Email::to_lowercase`.
+    pub fn to_lowercase(&self) -> Self {
+        Self::from(self.to_email().to_lowercase())
+    }
+
+    /// Returns a new ArcEmail, where the whole email has been uppercased.
+    /// See `Email::to_uppercase`.
+    pub fn to_uppercase(&self) -> Self {
+        Self::from(self.to_email().to_uppercase())
+    }
+
+    /// Returns if sending to this email needs the SMTPUTF8 extension,
+    /// which is when the local part contains non-ASCII characters.
+    pub fn requires_smtputf8(&self) -> bool {
+        self.as_email_ref().requires_smtputf8()
+    }
+
+    /// Returns if the domain contains non-ASCII characters,
+    /// and so needs converting with `Email::to_ascii_domain` for systems which only support ASCII.
+    pub fn requires_idna(&self) -> bool {
+        self.as_email_ref().requires_idna()
+    }
+
+    /// Returns a new ArcEmail, where the domain has been converted to ASCII.
+    /// See `Email::to_ascii_domain`.
+    pub fn to_ascii_domain(&self) -> Result<Self, EmailError> {
+        self.as_email_ref().to_ascii_domain().map(Self::from)
+    }
+
+    /// Returns a new ArcEmail, where the domain has been converted to ASCII,
+    /// validating the result using the rules of the policy.
+    pub fn to_ascii_domain_with<P>(&self, policy: P) -> Result<Self, EmailError>
+    where
+        P: Into<ValidationPolicy>,
+    {
+        self.as_email_ref()
+            .to_ascii_domain_with(policy)
+            .map(Self::from)
+    }
+
+    /// Returns a new ArcEmail, where the domain has been converted to Unicode.
+    /// See `Email::to_unicode_domain`.
+    pub fn to_unicode_domain(&self) -> Result<Self, EmailError> {
+        self.as_email_ref().to_unicode_domain().map(Self::from)
+    }
+
+    /// Returns a new ArcEmail, where the domain has been converted to Unicode,
+    /// validating the result using the rules of the policy.
+    pub fn to_unicode_domain_with<P>(&self, policy: P) -> Result<Self, EmailError>
+    where
+        P: Into<ValidationPolicy>,
+    {
+        self.as_email_ref()
+            .to_unicode_domain_with(policy)
+            .map(Self::from)
+    }
+
+    /// Returns the subaddress of this email, using `+` as the separator.
+    /// i.e. for `john+news@example.com` this returns `news`.
+    ///
+    /// Returns None if there is no subaddress, or if the local part is quoted.
+    pub fn subaddress(&self) -> Option<&str> {
+        self.as_email_ref().subaddress()
+    }
+
+    /// Returns the subaddress of this email, using the separator given.
+    /// i.e. for `john-news@example.com` with `-`, this returns `news`.
+    pub fn subaddress_separated_by(&self, separator: char) -> Option<&str> {
+        self.as_email_ref().subaddress_separated_by(separator)
+    }
+
+    /// Returns a new ArcEmail with the subaddress removed, using `+` as the separator.
+    /// i.e. `john+news@example.com` becomes `john@example.com`.
+    pub fn without_subaddress(&self) -> Self {
+        self.without_subaddress_separated_by('+')
+    }
+
+    /// Returns a new ArcEmail with the subaddress removed, using the separator given.
+    ///
+    /// If there is no subaddress, then this is a clone sharing the same text.
+    pub fn without_subaddress_separated_by(&self, separator: char) -> Self {
+        match self.subaddress_separated_by(separator) {
+            Some(_) => Self::from(self.to_email().without_subaddress_separated_by(separator)),
+            None => self.clone(),
+        }
+    }
+
+    /// Returns a new ArcEmail with the subaddress given, using `+` as the separator.
+    /// See `Email::with_subaddress`.
+    pub fn with_subaddress<S>(&self, subaddress: S) -> Result<Self, EmailError>
+    where
+        S: AsRef<str>,
+    {
+        self.with_subaddress_separated_by(subaddress, '+')
+    }
+
+    /// Returns a new ArcEmail with the subaddress given, using the separator given.
+    pub fn with_subaddress_separated_by<S>(
+        &self,
+        subaddress: S,
+        separator: char,
+    ) -> Result<Self, EmailError>
+    where
+        S: AsRef<str>,
+    {
+        self.as_email_ref()
+            .with_subaddress_separated_by(subaddress, separator)
+            .map(Self::from)
+    }
+
+    /// Returns a new ArcEmail with the subaddress given, using `+` as the separator,
+    /// validating the result using the rules of the policy.
+    pub fn with_subaddress_with<S, P>(&self, subaddress: S, policy: P) -> Result<Self, EmailError>
+    where
+        S: AsRef<str>,
+        P: Into<ValidationPolicy>,
+    {
+        self.with_subaddress_separated_by_with(subaddress, '+', policy)
+    }
+
+    /// Returns a new ArcEmail with the subaddress given, using the separator given,
+    /// validating the result using the rules of the policy.
+    pub fn with_subaddress_separated_by_with<S, P>(
+        &self,
+        subaddress: S,
+        separator: char,
+        policy: P,
+    ) -> Result<Self, EmailError>
+    where
+        S: AsRef<str>,
+        P: Into<ValidationPolicy>,
+    {
+        self.as_email_ref()
+            .with_subaddress_separated_by_with(subaddress, separator, policy)
+            .map(Self::from)
+    }
+
+    /// Returns a canonical form of this email, for use in finding duplicate sign ups.
+    /// See `Email::canonical_for_provider`.
+    pub fn canonical_for_provider(&self) -> Result<Self, EmailError> {
+        self.canonical_for_provider_with(&ProviderRules::new())
+    }
+
+    /// Returns a canonical form of this email, using the rules given.
+    pub fn canonical_for_provider_with(&self, rules: &ProviderRules) -> Result<Self, EmailError> {
+        rules.canonicalize(&self.to_email()).map(Self::from)
+    }
+
+    /// Returns a `mailto:` URI for this email.
+    pub fn to_mailto_uri(&self) -> MailtoUri {
+        self.to_email().to_mailto_uri()
+    }
+
+    /// Returns the kind of domain this email has,
+    /// such as a domain name, or an IP address literal.
+    pub fn domain_kind(&self) -> Domain {
+        self.as_email_ref().domain_kind()
+    }
+
+    /// Returns true if the domain belongs to a disposable email service,
+    /// using the built in list of `DisposableDomains`.
+    #[cfg(feature = "disposable")]
+    pub fn is_disposable(&self) -> bool {
+        self.as_email_ref().is_disposable()
+    }
+
+    /// Returns the public suffix of the domain, using the Public Suffix List.
+    #[cfg(feature = "public-suffix")]
+    pub fn public_suffix(&self) -> Option<&str> {
+        self.as_email_ref().public_suffix()
+    }
+
+    /// Returns the part of the domain that can be registered,
+    /// which is the public suffix with one label before it.
+    #[cfg(feature = "public-suffix")]
+    pub fn registrable_domain(&self) -> Option<&str> {
+        self.as_email_ref().registrable_domain()
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.raw_email
+    }
+
+    /// Returns the part of the email before the `@`.
+    ///
+    /// Quoted local parts are returned with their quotes,
+    /// i.e. for `"john@home"@example.com` this returns `"john@home"`.
+    pub fn local_part(&self) -> &str {
+        self.as_email_ref().local_part()
+    }
+
+    /// Returns the part of the email after the `@`.
+    ///
+    /// Domain literals are returned with their brackets,
+    /// i.e. for `john@[192.168.0.1]` this returns `[192.168.0.1]`.
+    pub fn domain(&self) -> &str {
+        self.as_email_ref().domain()
+    }
+}
+
+impl Display for ArcEmail {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.raw_email)
+    }
+}
+
+impl From<Email> for ArcEmail {
+    fn from(email: Email) -> Self {
+        Self::from(email.as_email_ref())
+    }
+}
+
+impl<'a> From<EmailRef<'a>> for ArcEmail {
+    fn from(email: EmailRef<'a>) -> Self {
+        Self {
+            raw_email: Arc::from(email.as_str()),
+            at_index: email.local_part().len(),
+        }
+    }
+}
+
+impl From<ArcEmail> for Email {
+    fn from(email: ArcEmail) -> Self {
+        Email::from(email.as_email_ref())
+    }
+}
+
+impl From<ArcEmail> for String {
+    fn from(email: ArcEmail) -> Self {
+        email.raw_email.to_string()
+    }
+}
+
+impl AsRef<str> for ArcEmail {
+    fn as_ref(&self) -> &str {
+        &self.raw_email
+    }
+}
+
+impl FromStr for ArcEmail {
+    type Err = EmailError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ArcEmail::from_str(s)
+    }
+}
+
+impl TryFrom<String> for ArcEmail {
+    type Error = EmailError;
+
+    fn try_from(raw: String) -> Result<Self, Self::Error> {
+        ArcEmail::from_string(raw)
+    }
+}
+
+impl<'a> TryFrom<&'a str> for ArcEmail {
+    type Error = EmailError;
+
+    fn try_from(raw: &'a str) -> Result<Self, Self::Error> {
+        ArcEmail::from_str(raw)
+    }
+}
+
+impl<'a> PartialEq<&'a str> for ArcEmail {
+    fn eq(&self, other: &&'a str) -> bool {
+        &*self.raw_email == *other
+    }
+}
+
+impl PartialEq<String> for ArcEmail {
+    fn eq(&self, other: &String) -> bool {
+        *self.raw_email == **other
+    }
+}
+
+impl PartialEq<Email> for ArcEmail {
+    fn eq(&self, other: &Email) -> bool {
+        &*self.raw_email == other.as_str()
+    }
+}
+
+impl PartialEq<ArcEmail> for Email {
+    fn eq(&self, other: &ArcEmail) -> bool {
+        self.as_str() == &*other.raw_email
+    }
+}
+
+#[cfg(test)]
+mod test_from_str {
+    use super::*;
+    use crate::EmailErrorKind;
+
+    #[test]
+    fn it_should_create_valid_emails() {
+        let email = ArcEmail::from_str("john@example.com").unwrap();
+
+        assert_eq!(email, "john@example.com");
+    }
+
+    #[test]
+    fn it_should_reject_invalid_emails() {
+        let err = ArcEmail::from_str("john.example.com").unwrap_err();
+
+        assert_eq!(err.kind(), &EmailErrorKind::MissingSeparator);
+    }
+}
+
+#[cfg(test)]
+mod test_clone {
+    use super::*;
+
+    #[test]
+    fn it_should_share_the_text_between_clones() {
+        let email = ArcEmail::from_str("john@example.com").unwrap();
+        let clone = email.clone();
+
+        assert!(::core::ptr::eq(email.as_str(), clone.as_str()));
+    }
+}
+
+#[cfg(test)]
+mod test_accessors {
+    use super::*;
+
+    #[test]
+    fn it_should_return_the_same_as_email() {
+        let raw = r#""jöhn@home"@exämple.com"#;
+        let arc_email = ArcEmail::from_str(raw).unwrap();
+        let email = Email::from_str(raw).unwrap();
+
+        assert_eq!(arc_email.local_part(), email.local_part());
+        assert_eq!(arc_email.domain(), email.domain());
+        assert_eq!(arc_email.subaddress(), email.subaddress());
+        assert_eq!(arc_email.requires_smtputf8(), email.requires_smtputf8());
+        assert_eq!(arc_email.requires_idna(), email.requires_idna());
+    }
+}
+
+#[cfg(test)]
+mod test_builders {
+    use super::*;
+
+    #[test]
+    fn it_should_return_the_same_as_email() {
+        let raw = "John.Smith+news@Bücher.de";
+        let arc_email = ArcEmail::from_str(raw).unwrap();
+        let email = Email::from_str(raw).unwrap();
+
+        assert_eq!(arc_email.to_lowercase(), email.to_lowercase());
+        assert_eq!(arc_email.to_uppercase(), email.to_uppercase());
+        assert_eq!(
+            arc_email.to_ascii_domain(),
+            email.to_ascii_domain().map(ArcEmail::from)
+        );
+        assert_eq!(
+            arc_email.to_ascii_domain().unwrap().to_unicode_domain(),
+            email
+                .to_ascii_domain()
+                .unwrap()
+                .to_unicode_domain()
+                .map(ArcEmail::from)
+        );
+        assert_eq!(arc_email.without_subaddress(), email.without_subaddress());
+        assert_eq!(
+            arc_email.with_subaddress("x"),
+            email.with_subaddress("x").map(ArcEmail::from)
+        );
+        assert_eq!(
+            arc_email.canonical_for_provider(),
+            email.canonical_for_provider().map(ArcEmail::from)
+        );
+        assert_eq!(arc_email.to_mailto_uri(), email.to_mailto_uri());
+    }
+
+    #[test]
+    fn it_should_share_the_text_when_there_is_no_subaddress() {
+        let email = ArcEmail::from_str("john@example.com").unwrap();
+        let without = email.without_subaddress();
+
+        assert!(::core::ptr::eq(email.as_str(), without.as_str()));
+    }
+}
+
+#[cfg(test)]
+mod test_conversions {
+    use super::*;
+
+    #[test]
+    fn it_should_convert_to_and_from_email() {
+        let email = Email::from_str("john+news@example.com").unwrap();
+        let arc_email = ArcEmail::from(email.clone());
+
+        assert_eq!(arc_email, email);
+        assert_eq!(Email::from(arc_email), email);
+    }
+
+    #[test]
+    fn it_should_convert_to_string() {
+        let email = ArcEmail::from_str("john@example.com").unwrap();
+
+        assert_eq!(String::from(email), "john@example.com");
+    }
+}
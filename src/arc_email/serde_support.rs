@@ -0,0 +1,53 @@
+use ::serde::Deserialize;
+use ::serde::Deserializer;
+use ::serde::Serialize;
+use ::serde::Serializer;
+
+use crate::ArcEmail;
+use crate::Email;
+
+impl Serialize for ArcEmail {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ArcEmail {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Email::deserialize(deserializer).map(ArcEmail::from)
+    }
+}
+
+#[cfg(test)]
+mod test_serde {
+    use super::*;
+    use ::serde_json;
+
+    #[test]
+    fn it_should_serialise_as_a_string() {
+        let email = ArcEmail::from_str("john@example.com").unwrap();
+
+        assert_eq!(
+            serde_json::to_string(&email).unwrap(),
+            r#""john@example.com""#
+        );
+    }
+
+    #[test]
+    fn it_should_deserialise_from_a_string() {
+        let email: ArcEmail = serde_json::from_str(r#""john@example.com""#).unwrap();
+
+        assert_eq!(email, "john@example.com");
+    }
+
+    #[test]
+    fn it_should_not_deserialise_invalid_emails() {
+        assert!(serde_json::from_str::<ArcEmail>(r#""donkeys""#).is_err());
+    }
+}
@@ -0,0 +1,218 @@
+use ::core::cmp::Ordering;
+use ::core::convert::AsRef;
+use ::core::convert::From;
+use ::core::fmt::Display;
+use ::core::fmt::Formatter;
+use ::core::fmt::Result as FmtResult;
+use ::core::hash::Hash;
+use ::core::hash::Hasher;
+use ::core::ops::Deref;
+
+use crate::Email;
+
+#[cfg(feature = "serde")]
+mod serde_support;
+
+/// An Email where the domain is compared case insensitively.
+///
+/// Domains are case insensitive, whilst the local part is not.
+/// This wraps an Email so that `bob@Example.com` and `bob@example.com` are equal,
+/// hash the same, and sort together. `BoB@example.com` is still different.
+///
+/// The original Email is kept as it was given,
+/// and is available through `Deref` or `CaselessDomainEmail::into_email`.
+///
+/// ```rust
+/// use ::serde_email::CaselessDomainEmail;
+/// use ::serde_email::Email;
+///
+/// let a = CaselessDomainEmail::from(Email::from_str("bob@Example.com").unwrap());
+/// let b = CaselessDomainEmail::from(Email::from_str("bob@example.com").unwrap());
+///
+/// assert_eq!(a, b);
+/// assert_eq!(a.as_str(), "bob@Example.com");
+/// ```
+#[derive(Clone, Debug)]
+pub struct CaselessDomainEmail {
+    email: Email,
+}
+
+impl CaselessDomainEmail {
+    pub fn new(email: Email) -> Self {
+        Self { email }
+    }
+
+    pub fn as_email(&self) -> &Email {
+        &self.email
+    }
+
+    pub fn into_email(self) -> Email {
+        self.email
+    }
+
+    fn caseless_domain(&self) -> impl Iterator<Item = char> + '_ {
+        self.email.domain().chars().flat_map(char::to_lowercase)
+    }
+}
+
+impl Deref for CaselessDomainEmail {
+    type Target = Email;
+
+    fn deref(&self) -> &Email {
+        &self.email
+    }
+}
+
+impl PartialEq for CaselessDomainEmail {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for CaselessDomainEmail {}
+
+impl PartialOrd for CaselessDomainEmail {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Orders by the local part first, and then the lowercased domain.
+impl Ord for CaselessDomainEmail {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.email
+            .local_part()
+            .cmp(other.email.local_part())
+            .then_with(|| self.caseless_domain().cmp(other.caseless_domain()))
+    }
+}
+
+impl Hash for CaselessDomainEmail {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.email.local_part().hash(state);
+        for c in self.caseless_domain() {
+            c.hash(state);
+        }
+    }
+}
+
+impl Display for CaselessDomainEmail {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.email)
+    }
+}
+
+impl From<Email> for CaselessDomainEmail {
+    fn from(email: Email) -> Self {
+        Self::new(email)
+    }
+}
+
+impl From<CaselessDomainEmail> for Email {
+    fn from(email: CaselessDomainEmail) -> Self {
+        email.email
+    }
+}
+
+impl AsRef<str> for CaselessDomainEmail {
+    fn as_ref(&self) -> &str {
+        self.email.as_str()
+    }
+}
+
+impl AsRef<Email> for CaselessDomainEmail {
+    fn as_ref(&self) -> &Email {
+        &self.email
+    }
+}
+
+#[cfg(test)]
+mod test_partial_eq {
+    use super::*;
+
+    fn caseless(raw: &str) -> CaselessDomainEmail {
+        Email::from_str(raw).unwrap().into()
+    }
+
+    #[test]
+    fn it_should_ignore_the_case_of_the_domain() {
+        assert_eq!(caseless("bob@Example.COM"), caseless("bob@example.com"));
+    }
+
+    #[test]
+    fn it_should_ignore_the_case_of_unicode_domains() {
+        assert_eq!(caseless("bob@BÜCHER.de"), caseless("bob@bücher.de"));
+    }
+
+    #[test]
+    fn it_should_not_ignore_the_case_of_the_local_part() {
+        assert_ne!(caseless("BoB@example.com"), caseless("bob@example.com"));
+    }
+
+    #[test]
+    fn it_should_not_equal_different_domains() {
+        assert_ne!(caseless("bob@example.com"), caseless("bob@example.org"));
+    }
+}
+
+#[cfg(test)]
+mod test_hash {
+    use super::*;
+    use ::std::collections::HashSet;
+
+    #[test]
+    fn it_should_hash_domains_the_same_regardless_of_case() {
+        let mut emails = HashSet::new();
+        emails.insert(CaselessDomainEmail::from(
+            Email::from_str("bob@Example.com").unwrap(),
+        ));
+        emails.insert(CaselessDomainEmail::from(
+            Email::from_str("bob@example.com").unwrap(),
+        ));
+        emails.insert(CaselessDomainEmail::from(
+            Email::from_str("BoB@example.com").unwrap(),
+        ));
+
+        assert_eq!(emails.len(), 2);
+    }
+}
+
+#[cfg(test)]
+mod test_ord {
+    use super::*;
+
+    fn caseless(raw: &str) -> CaselessDomainEmail {
+        Email::from_str(raw).unwrap().into()
+    }
+
+    #[test]
+    fn it_should_be_equal_when_only_the_domain_case_differs() {
+        assert_eq!(
+            caseless("bob@Example.com").cmp(&caseless("bob@example.com")),
+            Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn it_should_order_by_local_part_first() {
+        assert!(caseless("alice@zzz.com") < caseless("bob@AAA.com"));
+    }
+
+    #[test]
+    fn it_should_order_domains_ignoring_case() {
+        assert!(caseless("bob@AAA.com") < caseless("bob@bbb.com"));
+        assert!(caseless("bob@aaa.com") < caseless("bob@BBB.com"));
+    }
+}
+
+#[cfg(test)]
+mod test_display {
+    use super::*;
+
+    #[test]
+    fn it_should_write_the_original_email() {
+        let email = CaselessDomainEmail::from(Email::from_str("bob@Example.com").unwrap());
+
+        assert_eq!(email.to_string(), "bob@Example.com");
+    }
+}
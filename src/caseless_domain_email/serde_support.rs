@@ -0,0 +1,59 @@
+use ::serde::Deserialize;
+use ::serde::Deserializer;
+use ::serde::Serialize;
+use ::serde::Serializer;
+
+use crate::CaselessDomainEmail;
+use crate::Email;
+
+impl Serialize for CaselessDomainEmail {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_email().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for CaselessDomainEmail {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Email::deserialize(deserializer).map(CaselessDomainEmail::new)
+    }
+}
+
+#[cfg(test)]
+mod test_serialisation {
+    use super::*;
+    use ::serde_json;
+
+    #[test]
+    fn it_should_serialise_the_original_email() {
+        let email = CaselessDomainEmail::from(Email::from_str("john@Example.com").unwrap());
+        let raw = serde_json::to_string(&email).unwrap();
+
+        assert_eq!(raw, r#""john@Example.com""#);
+    }
+}
+
+#[cfg(test)]
+mod test_deserialisation {
+    use super::*;
+    use ::serde_json;
+
+    #[test]
+    fn it_should_deserialise_email_from_string() {
+        let email: CaselessDomainEmail = serde_json::from_str(r#""john@Example.com""#).unwrap();
+
+        assert_eq!(email.as_str(), "john@Example.com");
+    }
+
+    #[test]
+    fn it_should_not_deserialise_non_email_from_string() {
+        let result = serde_json::from_str::<CaselessDomainEmail>(r#""donkeys""#);
+
+        assert!(result.is_err());
+    }
+}
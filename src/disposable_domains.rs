@@ -0,0 +1,292 @@
+use ::alloc::collections::BTreeSet;
+use ::alloc::string::String;
+use ::alloc::string::ToString;
+use ::idna::domain_to_ascii;
+
+use crate::EmailError;
+use crate::EmailErrorKind;
+use crate::EmailRef;
+
+mod built_in {
+    include!(concat!(env!("OUT_DIR"), "/disposable_domains.rs"));
+}
+
+/// A set of domains belonging to disposable, or throwaway, email services.
+///
+/// `DisposableDomains::new` includes a built in list of well known services.
+/// Further domains can be added at runtime, such as from a list maintained by your team.
+/// Subdomains of a domain in the set are also treated as disposable.
+///
+/// Domains are compared in their ASCII form, so `bücher.example`
+/// and `xn--bcher-kva.example` are the same domain.
+///
+/// ```rust
+/// use ::serde_email::DisposableDomains;
+/// use ::serde_email::Email;
+///
+/// let domains = DisposableDomains::new().with_list("
+///     # Our own list
+///     throwaway.example.com
+/// ");
+///
+/// assert!(domains.is_disposable(&Email::from_str("john@mailinator.com").unwrap()));
+/// assert!(domains.is_disposable(&Email::from_str("john@mail.throwaway.example.com").unwrap()));
+/// assert!(!domains.is_disposable(&Email::from_str("john@example.com").unwrap()));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DisposableDomains {
+    built_in: &'static [&'static str],
+    domains: BTreeSet<String>,
+}
+
+impl DisposableDomains {
+    /// Creates a set holding the built in list of disposable domains.
+    pub fn new() -> Self {
+        Self {
+            built_in: built_in::DISPOSABLE_DOMAINS,
+            domains: BTreeSet::new(),
+        }
+    }
+
+    /// Creates a set with no domains.
+    pub fn empty() -> Self {
+        Self {
+            built_in: &[],
+            domains: BTreeSet::new(),
+        }
+    }
+
+    /// Adds a domain to the set.
+    pub fn with_domain<S>(mut self, domain: S) -> Self
+    where
+        S: AsRef<str>,
+    {
+        self.domains.insert(to_lookup_form(domain.as_ref().trim()));
+        self
+    }
+
+    /// Adds all of the domains given to the set.
+    pub fn with_domains<I, S>(self, domains: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        domains
+            .into_iter()
+            .fold(self, |this, domain| this.with_domain(domain))
+    }
+
+    /// Adds the domains from a list, such as the contents of a file.
+    ///
+    /// The list has one domain per line.
+    /// Blank lines, and lines starting with `#`, are skipped.
+    pub fn with_list(self, list: &str) -> Self {
+        let domains = list
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'));
+
+        self.with_domains(domains)
+    }
+
+    /// Returns true if the domain given, or a domain it is a subdomain of, is in the set.
+    pub fn contains<S>(&self, domain: S) -> bool
+    where
+        S: AsRef<str>,
+    {
+        is_disposable_domain(domain.as_ref(), |domain| {
+            self.built_in.binary_search(&domain).is_ok() || self.domains.contains(domain)
+        })
+    }
+
+    /// Returns true if the domain of the email is disposable.
+    ///
+    /// This takes an `&Email` or an `EmailRef`.
+    pub fn is_disposable<'e, E>(&self, email: E) -> bool
+    where
+        E: Into<EmailRef<'e>>,
+    {
+        self.contains(email.into().domain())
+    }
+
+    /// Returns an error if the domain of the email is disposable.
+    ///
+    /// This is for rejecting addresses using domains added at runtime.
+    /// `ValidationPolicy::reject_disposable_domains` only uses the built in list.
+    pub fn check<'e, E>(&self, email: E) -> Result<(), EmailError>
+    where
+        E: Into<EmailRef<'e>>,
+    {
+        let email = email.into();
+        if !self.is_disposable(email) {
+            return Ok(());
+        }
+
+        Err(EmailError::Invalid {
+            raw_email: email.to_string(),
+            kind: EmailErrorKind::DisposableDomain {
+                position: email.local_part().len() + 1,
+            },
+        })
+    }
+}
+
+/// Same as `DisposableDomains::new`.
+impl Default for DisposableDomains {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns true if the domain given is in the built in list of disposable domains.
+pub(crate) fn is_built_in_disposable_domain(domain: &str) -> bool {
+    is_disposable_domain(domain, |domain| {
+        built_in::DISPOSABLE_DOMAINS.binary_search(&domain).is_ok()
+    })
+}
+
+/// Checks the domain, and each domain it is a subdomain of, against the list.
+/// Domain literals are never disposable.
+fn is_disposable_domain<F>(domain: &str, is_listed: F) -> bool
+where
+    F: Fn(&str) -> bool,
+{
+    if domain.starts_with('[') {
+        return false;
+    }
+
+    let domain = to_lookup_form(domain);
+    let parent_domains = domain
+        .match_indices('.')
+        .map(|(index, _)| &domain[index + 1..]);
+
+    ::core::iter::once(domain.as_str())
+        .chain(parent_domains)
+        .any(is_listed)
+}
+
+/// Returns the domain in the form it is listed in,
+/// which is lowercase ASCII using the UTS #46 IDNA mapping.
+/// Domains which cannot be mapped are only lowercased.
+fn to_lookup_form(domain: &str) -> String {
+    domain_to_ascii(domain).unwrap_or_else(|_| domain.to_lowercase())
+}
+
+#[cfg(test)]
+mod test_contains {
+    use super::*;
+
+    #[test]
+    fn it_should_contain_built_in_domains() {
+        let domains = DisposableDomains::new();
+
+        assert!(domains.contains("mailinator.com"));
+        assert!(domains.contains("guerrillamail.com"));
+        assert!(!domains.contains("example.com"));
+    }
+
+    #[test]
+    fn it_should_match_subdomains() {
+        let domains = DisposableDomains::new();
+
+        assert!(domains.contains("mail.mailinator.com"));
+        assert!(!domains.contains("notreallymailinator.com"));
+        assert!(!domains.contains("com"));
+    }
+
+    #[test]
+    fn it_should_ignore_case() {
+        assert!(DisposableDomains::new().contains("MAILINATOR.com"));
+        assert!(DisposableDomains::empty()
+            .with_domain("Throwaway.Example.com")
+            .contains("throwaway.EXAMPLE.com"));
+    }
+
+    #[test]
+    fn it_should_match_unicode_and_punycode_domains() {
+        let unicode = DisposableDomains::empty().with_domain("bücher.example");
+        let punycode = DisposableDomains::empty().with_domain("xn--bcher-kva.example");
+
+        assert!(unicode.contains("xn--bcher-kva.example"));
+        assert!(unicode.contains("mail.BÜCHER.example"));
+        assert!(punycode.contains("bücher.example"));
+    }
+
+    #[test]
+    fn it_should_not_contain_domain_literals() {
+        let domains = DisposableDomains::empty().with_domain("[192.168.0.1]");
+
+        assert!(!domains.contains("[192.168.0.1]"));
+    }
+
+    #[test]
+    fn it_should_contain_nothing_when_empty() {
+        assert!(!DisposableDomains::empty().contains("mailinator.com"));
+    }
+}
+
+#[cfg(test)]
+mod test_with_list {
+    use super::*;
+
+    #[test]
+    fn it_should_add_each_domain_in_the_list() {
+        let domains = DisposableDomains::empty().with_list(
+            "
+            # Comments are skipped
+            throwaway.example.com
+
+              spam.example.net
+            ",
+        );
+
+        assert!(domains.contains("throwaway.example.com"));
+        assert!(domains.contains("spam.example.net"));
+        assert!(!domains.contains("example.com"));
+        assert!(!domains.contains("# Comments are skipped"));
+    }
+
+    #[test]
+    fn it_should_keep_the_built_in_domains() {
+        let domains = DisposableDomains::new().with_list("throwaway.example.com");
+
+        assert!(domains.contains("mailinator.com"));
+        assert!(domains.contains("throwaway.example.com"));
+    }
+}
+
+#[cfg(test)]
+mod test_check {
+    use super::*;
+    use crate::Email;
+
+    #[test]
+    fn it_should_accept_other_domains() {
+        let email = Email::from_str("john@example.com").unwrap();
+
+        assert!(DisposableDomains::new().check(&email).is_ok());
+    }
+
+    #[test]
+    fn it_should_reject_disposable_domains() {
+        let email = Email::from_str("john@throwaway.example.com").unwrap();
+        let err = DisposableDomains::new()
+            .with_domain("throwaway.example.com")
+            .check(&email)
+            .unwrap_err();
+
+        assert_eq!(
+            err.kind(),
+            &EmailErrorKind::DisposableDomain { position: 5 }
+        );
+    }
+
+    #[test]
+    fn it_should_take_an_email_ref() {
+        let email = EmailRef::from_str("john@xn--bcher-kva.example").unwrap();
+        let domains = DisposableDomains::empty().with_domain("bücher.example");
+
+        assert!(domains.is_disposable(email));
+        assert!(domains.check(email).is_err());
+    }
+}
@@ -0,0 +1,139 @@
+use ::core::net::Ipv4Addr;
+use ::core::net::Ipv6Addr;
+
+use crate::parse_email::parse_literal_address;
+
+/// The kind of domain an email has, returned from `Email::domain_kind`.
+///
+/// ```rust
+/// use ::serde_email::Domain;
+/// use ::serde_email::Email;
+/// use ::std::net::Ipv4Addr;
+///
+/// let email = Email::from_str("john@[192.168.0.1]").unwrap();
+///
+/// assert_eq!(email.domain_kind(), Domain::Ipv4(Ipv4Addr::new(192, 168, 0, 1)));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Domain {
+    /// A domain name, such as `example.com`.
+    Name,
+
+    /// An IPv4 address literal, such as `[192.168.0.1]`.
+    Ipv4(Ipv4Addr),
+
+    /// An IPv6 address literal, such as `[IPv6:2001:db8::1]`.
+    Ipv6(Ipv6Addr),
+
+    /// Any other domain literal, such as `[tag:content]`.
+    General,
+}
+
+impl Domain {
+    /// Works out the kind of a domain which has already been validated.
+    pub(crate) fn of(domain: &str) -> Self {
+        domain
+            .strip_prefix('[')
+            .and_then(|domain| domain.strip_suffix(']'))
+            .map(|literal| parse_literal_address(literal, 0).unwrap_or(Domain::General))
+            .unwrap_or(Domain::Name)
+    }
+
+    /// Returns true if this is an IP address which cannot be reached over the internet.
+    ///
+    /// This covers private networks, such as `10.0.0.1` and `fd00::1`,
+    /// loopback and link local addresses, and the unspecified address.
+    /// IPv4 addresses mapped into IPv6 are checked as IPv4.
+    pub fn is_private(&self) -> bool {
+        match *self {
+            Domain::Ipv4(ip) => is_private_ipv4(ip),
+            Domain::Ipv6(ip) => match ip.to_ipv4_mapped() {
+                Some(ipv4) => is_private_ipv4(ipv4),
+                None => {
+                    ip.is_loopback()
+                        || ip.is_unspecified()
+                        || ip.is_unique_local()
+                        || ip.is_unicast_link_local()
+                }
+            },
+            Domain::Name | Domain::General => false,
+        }
+    }
+}
+
+fn is_private_ipv4(ip: Ipv4Addr) -> bool {
+    ip.is_private() || ip.is_loopback() || ip.is_link_local() || ip.is_unspecified()
+}
+
+#[cfg(test)]
+mod test_of {
+    use super::*;
+
+    #[test]
+    fn it_should_find_domain_names() {
+        assert_eq!(Domain::of("example.com"), Domain::Name);
+    }
+
+    #[test]
+    fn it_should_find_ipv4_literals() {
+        assert_eq!(
+            Domain::of("[192.168.0.1]"),
+            Domain::Ipv4(Ipv4Addr::new(192, 168, 0, 1))
+        );
+    }
+
+    #[test]
+    fn it_should_find_ipv6_literals() {
+        assert_eq!(
+            Domain::of("[IPv6:2001:db8::1]"),
+            Domain::Ipv6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1))
+        );
+    }
+
+    #[test]
+    fn it_should_find_general_literals() {
+        assert_eq!(Domain::of("[tag:content]"), Domain::General);
+        assert_eq!(Domain::of("[anything]"), Domain::General);
+    }
+}
+
+#[cfg(test)]
+mod test_is_private {
+    use super::*;
+
+    fn is_private(domain: &str) -> bool {
+        Domain::of(domain).is_private()
+    }
+
+    #[test]
+    fn it_should_find_private_ipv4_addresses() {
+        assert!(is_private("[10.0.0.1]"));
+        assert!(is_private("[172.16.0.1]"));
+        assert!(is_private("[192.168.0.1]"));
+        assert!(is_private("[127.0.0.1]"));
+        assert!(is_private("[169.254.0.1]"));
+        assert!(is_private("[0.0.0.0]"));
+    }
+
+    #[test]
+    fn it_should_find_private_ipv6_addresses() {
+        assert!(is_private("[IPv6:::1]"));
+        assert!(is_private("[IPv6:::]"));
+        assert!(is_private("[IPv6:fd00::1]"));
+        assert!(is_private("[IPv6:fe80::1]"));
+        assert!(is_private("[IPv6:::ffff:192.168.0.1]"));
+    }
+
+    #[test]
+    fn it_should_not_find_public_addresses() {
+        assert!(!is_private("[8.8.8.8]"));
+        assert!(!is_private("[IPv6:2001:4860:4860::8888]"));
+        assert!(!is_private("[IPv6:::ffff:8.8.8.8]"));
+    }
+
+    #[test]
+    fn it_should_not_find_names_or_general_literals() {
+        assert!(!is_private("localhost"));
+        assert!(!is_private("[tag:10.0.0.1]"));
+    }
+}
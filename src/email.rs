@@ -1,13 +1,23 @@
-use ::std::convert::AsRef;
-use ::std::convert::From;
-use ::std::fmt::Debug;
-use ::std::fmt::Display;
-use ::std::fmt::Formatter;
-use ::std::fmt::Result as FmtResult;
-use ::std::str::FromStr;
-
-use crate::is_valid_email;
+use ::alloc::borrow::Cow;
+use ::alloc::format;
+use ::alloc::string::String;
+use ::alloc::string::ToString;
+use ::core::convert::AsRef;
+use ::core::convert::From;
+use ::core::fmt::Debug;
+use ::core::fmt::Display;
+use ::core::fmt::Formatter;
+use ::core::fmt::Result as FmtResult;
+use ::core::str::FromStr;
+
+use crate::parse_email::parse_email;
+use crate::split_subaddress::split_subaddress;
+use crate::Domain;
 use crate::EmailError;
+use crate::EmailRef;
+use crate::MailtoUri;
+use crate::ProviderRules;
+use crate::ValidationPolicy;
 
 #[cfg(feature = "serde")]
 mod email_visitor;
@@ -27,32 +37,55 @@ mod sea_orm_support;
 ///
 /// Once built you can turn this like a `String`, use with Serde, or Sea Orm.
 ///
+/// With the default `ValidationPolicy`, an Email is at most 254 bytes long,
+/// with a local part of at most 64 bytes, as per RFC 5321.
+///
+/// The position of the `@` separating the local part from the domain is
+/// found when the Email is created, so `Email::local_part` and `Email::domain`
+/// are cheap to call.
+///
 /// Note that Email objects _are_ case sensetive.
 /// The email addresses `Email::from_str("bob@example.com")` and `Email::from_str("BoB@example.com")`,
 /// will not be equal to each other.
+/// Use `CaselessDomainEmail` to compare with the domain being case insensitive.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Email {
-    raw_email: String,
+    raw_email: Cow<'static, str>,
+    at_index: usize,
 }
 
 impl Email {
     /// Creates a new Email, from the `String` given.
     ///
     /// If the given string doesn't look like a valid email,
-    /// then this will return an EmailError.
+    /// then this will return an EmailError describing why.
     pub fn from_string(raw_email: String) -> Result<Self, EmailError> {
-        if !is_valid_email(&raw_email) {
-            let err = EmailError::Invalid { raw_email };
-            return Err(err);
-        }
+        Self::from_string_with(raw_email, ValidationPolicy::new())
+    }
 
-        Ok(Self { raw_email })
+    /// Creates a new Email, from the `String` given,
+    /// validating it using the rules of the policy.
+    ///
+    /// If the given string doesn't meet the policy,
+    /// then this will return an EmailError describing why.
+    pub fn from_string_with<P>(raw_email: String, policy: P) -> Result<Self, EmailError>
+    where
+        P: Into<ValidationPolicy>,
+    {
+        match parse_email(&raw_email, &policy.into()) {
+            Ok(at_index) => Ok(Self {
+                raw_email: Cow::Owned(raw_email),
+                at_index,
+            }),
+            Err(kind) => Err(EmailError::Invalid { raw_email, kind }),
+        }
     }
 
     /// Creates a new Email, from the `str` given.
     ///
     /// If the given string doesn't look like a valid email,
     /// then this will return an EmailError.
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str<S>(raw_email: S) -> Result<Self, EmailError>
     where
         S: AsRef<str>,
@@ -60,23 +93,357 @@ impl Email {
         Self::from_string(raw_email.as_ref().to_string())
     }
 
-    /// Returns a new Email, where the email has been uppercased.
-    pub fn to_lowercase(&self) -> Self {
+    /// Creates a new Email, from the `str` given,
+    /// validating it using the rules of the policy.
+    ///
+    /// If the given string doesn't meet the policy,
+    /// then this will return an EmailError describing why.
+    pub fn from_str_with<S, P>(raw_email: S, policy: P) -> Result<Self, EmailError>
+    where
+        S: AsRef<str>,
+        P: Into<ValidationPolicy>,
+    {
+        Self::from_string_with(raw_email.as_ref().to_string(), policy)
+    }
+
+    /// Creates a new Email from a `&'static str`, without validating it.
+    /// This is a `const fn`, so it can be used to build `static` and `const` emails.
+    ///
+    /// The email given must be valid. Use the `email!` macro,
+    /// from the `serde-email-macros` crate, to have it checked at compile time.
+    ///
+    /// ```rust
+    /// use ::serde_email::Email;
+    ///
+    /// static NOREPLY: Email = Email::from_static_unchecked("noreply@example.com");
+    ///
+    /// assert_eq!(NOREPLY.domain(), "example.com");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If the email has no `@` separating the local part from the domain.
+    pub const fn from_static_unchecked(raw_email: &'static str) -> Self {
         Self {
-            raw_email: self.raw_email.to_lowercase(),
+            raw_email: Cow::Borrowed(raw_email),
+            at_index: find_at_index(raw_email),
         }
     }
 
+    /// Builds an Email from a local part and domain which have already been validated.
+    pub(crate) fn from_valid_parts(local_part: String, domain: &str) -> Self {
+        let at_index = local_part.len();
+        let mut raw_email = local_part;
+        raw_email.push('@');
+        raw_email.push_str(domain);
+
+        Self {
+            raw_email: Cow::Owned(raw_email),
+            at_index,
+        }
+    }
+
+    /// Returns a new Email, where the whole email has been lowercased,
+    /// including the local part.
+    ///
+    /// Use a `Normalizer` to lowercase only the domain.
+    ///
+    /// Lowercasing some Unicode characters changes their length,
+    /// and can make the email invalid. In that case only ASCII characters are lowercased.
+    pub fn to_lowercase(&self) -> Self {
+        self.map_case(str::to_lowercase, str::to_ascii_lowercase)
+    }
+
     /// Returns a new Email, where the internal email has been uppercased.
+    ///
+    /// Uppercasing some Unicode characters changes their length,
+    /// and can make the email invalid. In that case only ASCII characters are uppercased.
     pub fn to_uppercase(&self) -> Self {
-        Self {
-            raw_email: self.raw_email.to_uppercase(),
+        self.map_case(str::to_uppercase, str::to_ascii_uppercase)
+    }
+
+    /// Maps the case of the email, and validates the result.
+    /// If it is invalid, then the ASCII mapping is used instead,
+    /// which never changes if an email is valid.
+    fn map_case(&self, f: fn(&str) -> String, ascii_f: fn(&str) -> String) -> Self {
+        let raw_email = format!("{}@{}", f(self.local_part()), f(self.domain()));
+
+        Self::from_string(raw_email).unwrap_or_else(|_| {
+            Self::from_valid_parts(ascii_f(self.local_part()), &ascii_f(self.domain()))
+        })
+    }
+
+    /// Returns if sending to this email needs the SMTPUTF8 extension,
+    /// which is when the local part contains non-ASCII characters.
+    ///
+    /// A non-ASCII domain alone does not need SMTPUTF8,
+    /// as it can be converted using `Email::to_ascii_domain`.
+    pub fn requires_smtputf8(&self) -> bool {
+        self.as_email_ref().requires_smtputf8()
+    }
+
+    /// Returns if the domain contains non-ASCII characters,
+    /// and so needs converting with `Email::to_ascii_domain` for systems which only support ASCII.
+    pub fn requires_idna(&self) -> bool {
+        self.as_email_ref().requires_idna()
+    }
+
+    /// Returns a new Email, where the domain has been converted to ASCII
+    /// using the UTS #46 IDNA mapping. The local part is left alone.
+    /// i.e. `user@bücher.de` becomes `user@xn--bcher-kva.de`.
+    ///
+    /// The mapping also lowercases the domain.
+    /// Domain literals, such as `[192.168.0.1]`, are left as they are.
+    ///
+    /// If a label in the domain cannot be converted,
+    /// then this will return an EmailError describing which one.
+    pub fn to_ascii_domain(&self) -> Result<Self, EmailError> {
+        self.as_email_ref().to_ascii_domain()
+    }
+
+    /// Returns a new Email, where the domain has been converted to ASCII,
+    /// validating the result using the rules of the policy.
+    ///
+    /// The result of `Email::to_ascii_domain` is validated with the default policy,
+    /// so use this for emails which were validated with a different one.
+    pub fn to_ascii_domain_with<P>(&self, policy: P) -> Result<Self, EmailError>
+    where
+        P: Into<ValidationPolicy>,
+    {
+        self.as_email_ref().to_ascii_domain_with(policy)
+    }
+
+    /// Returns a new Email, where the domain has been converted to Unicode
+    /// using the UTS #46 IDNA mapping. The local part is left alone.
+    /// i.e. `user@xn--bcher-kva.de` becomes `user@bücher.de`.
+    ///
+    /// If a label in the domain cannot be converted,
+    /// then this will return an EmailError describing which one.
+    pub fn to_unicode_domain(&self) -> Result<Self, EmailError> {
+        self.as_email_ref().to_unicode_domain()
+    }
+
+    /// Returns a new Email, where the domain has been converted to Unicode,
+    /// validating the result using the rules of the policy.
+    pub fn to_unicode_domain_with<P>(&self, policy: P) -> Result<Self, EmailError>
+    where
+        P: Into<ValidationPolicy>,
+    {
+        self.as_email_ref().to_unicode_domain_with(policy)
+    }
+
+    /// Returns the subaddress of this email, using `+` as the separator.
+    /// i.e. for `john+news@example.com` this returns `news`.
+    ///
+    /// Returns None if there is no subaddress, or if the local part is quoted.
+    pub fn subaddress(&self) -> Option<&str> {
+        self.subaddress_separated_by('+')
+    }
+
+    /// Returns the subaddress of this email, using the separator given.
+    /// i.e. for `john-news@example.com` with `-`, this returns `news`.
+    pub fn subaddress_separated_by(&self, separator: char) -> Option<&str> {
+        self.as_email_ref().subaddress_separated_by(separator)
+    }
+
+    /// Returns a new Email with the subaddress removed, using `+` as the separator.
+    /// i.e. `john+news@example.com` becomes `john@example.com`.
+    ///
+    /// If there is no subaddress, then the Email is returned unchanged.
+    pub fn without_subaddress(&self) -> Self {
+        self.without_subaddress_separated_by('+')
+    }
+
+    /// Returns a new Email with the subaddress removed, using the separator given.
+    pub fn without_subaddress_separated_by(&self, separator: char) -> Self {
+        match split_subaddress(self.local_part(), separator) {
+            Some((mailbox, _)) => Self::from_valid_parts(mailbox.to_string(), self.domain()),
+            None => self.clone(),
         }
     }
 
-    pub fn as_str<'a>(&'a self) -> &'a str {
+    /// Returns a new Email with the subaddress given, using `+` as the separator.
+    /// Any existing subaddress is replaced.
+    /// i.e. `john@example.com` with `news` becomes `john+news@example.com`.
+    ///
+    /// The result is validated with the default policy.
+    /// Use `Email::with_subaddress_with` for emails which were validated with a different one.
+    ///
+    /// If the subaddress would make the email invalid,
+    /// then this will return an EmailError describing why.
+    pub fn with_subaddress<S>(&self, subaddress: S) -> Result<Self, EmailError>
+    where
+        S: AsRef<str>,
+    {
+        self.with_subaddress_separated_by(subaddress, '+')
+    }
+
+    /// Returns a new Email with the subaddress given, using `+` as the separator,
+    /// validating the result using the rules of the policy.
+    ///
+    /// ```rust
+    /// use ::serde_email::Email;
+    /// use ::serde_email::ValidationMode;
+    ///
+    /// let email = Email::from_str_with(".john@example.com", ValidationMode::Html5).unwrap();
+    /// let email = email.with_subaddress_with("news", ValidationMode::Html5).unwrap();
+    ///
+    /// assert_eq!(email.as_str(), ".john+news@example.com");
+    /// ```
+    pub fn with_subaddress_with<S, P>(&self, subaddress: S, policy: P) -> Result<Self, EmailError>
+    where
+        S: AsRef<str>,
+        P: Into<ValidationPolicy>,
+    {
+        self.with_subaddress_separated_by_with(subaddress, '+', policy)
+    }
+
+    /// Returns a new Email with the subaddress given, using the separator given.
+    /// Any existing subaddress is replaced.
+    pub fn with_subaddress_separated_by<S>(
+        &self,
+        subaddress: S,
+        separator: char,
+    ) -> Result<Self, EmailError>
+    where
+        S: AsRef<str>,
+    {
+        self.as_email_ref()
+            .with_subaddress_separated_by(subaddress, separator)
+    }
+
+    /// Returns a new Email with the subaddress given, using the separator given,
+    /// validating the result using the rules of the policy.
+    pub fn with_subaddress_separated_by_with<S, P>(
+        &self,
+        subaddress: S,
+        separator: char,
+        policy: P,
+    ) -> Result<Self, EmailError>
+    where
+        S: AsRef<str>,
+        P: Into<ValidationPolicy>,
+    {
+        self.as_email_ref()
+            .with_subaddress_separated_by_with(subaddress, separator, policy)
+    }
+
+    /// Returns a canonical form of this email, for use in finding duplicate sign ups.
+    /// The Email itself is left unchanged.
+    ///
+    /// This uses the built in rules of `ProviderRules::new`.
+    /// i.e. `j.o.h.n+1@googlemail.com` becomes `john@gmail.com`.
+    ///
+    /// The result may not be the address the provider delivers to,
+    /// so use it as a key rather than for sending emails.
+    ///
+    /// Returns an error if the canonical form is not a valid email,
+    /// such as when lowercasing makes it too long.
+    pub fn canonical_for_provider(&self) -> Result<Self, EmailError> {
+        self.canonical_for_provider_with(&ProviderRules::new())
+    }
+
+    /// Returns a canonical form of this email, using the rules given.
+    pub fn canonical_for_provider_with(&self, rules: &ProviderRules) -> Result<Self, EmailError> {
+        rules.canonicalize(self)
+    }
+
+    /// Returns a `mailto:` URI for this email,
+    /// which can be extended with other recipients, a subject, and a body.
+    pub fn to_mailto_uri(&self) -> MailtoUri {
+        MailtoUri::new().with_to(self.clone())
+    }
+
+    /// Returns an `EmailRef` borrowing this email.
+    pub fn as_email_ref(&self) -> EmailRef<'_> {
+        EmailRef::from_valid_parts(&self.raw_email, self.at_index)
+    }
+
+    /// Returns the kind of domain this email has,
+    /// such as a domain name, or an IP address literal.
+    ///
+    /// ```rust
+    /// use ::serde_email::Domain;
+    /// use ::serde_email::Email;
+    ///
+    /// let email = Email::from_str("john@[IPv6:2001:db8::1]").unwrap();
+    ///
+    /// assert!(matches!(email.domain_kind(), Domain::Ipv6(_)));
+    /// ```
+    pub fn domain_kind(&self) -> Domain {
+        self.as_email_ref().domain_kind()
+    }
+
+    /// Returns true if the domain belongs to a disposable email service,
+    /// such as `mailinator.com`, or a subdomain of one.
+    ///
+    /// This uses the built in list of `DisposableDomains`.
+    ///
+    /// ```rust
+    /// use ::serde_email::Email;
+    ///
+    /// let email = Email::from_str("john@mailinator.com").unwrap();
+    ///
+    /// assert!(email.is_disposable());
+    /// ```
+    #[cfg(feature = "disposable")]
+    pub fn is_disposable(&self) -> bool {
+        self.as_email_ref().is_disposable()
+    }
+
+    /// Returns the public suffix of the domain, using the Public Suffix List.
+    /// Returns None for domain literals.
+    ///
+    /// This includes the private domains of the list,
+    /// so for `john@jane.github.io` this returns `github.io`.
+    ///
+    /// ```rust
+    /// use ::serde_email::Email;
+    ///
+    /// let email = Email::from_str("john@mail.example.co.uk").unwrap();
+    ///
+    /// assert_eq!(email.public_suffix(), Some("co.uk"));
+    /// ```
+    #[cfg(feature = "public-suffix")]
+    pub fn public_suffix(&self) -> Option<&str> {
+        self.as_email_ref().public_suffix()
+    }
+
+    /// Returns the part of the domain that can be registered,
+    /// which is the public suffix with one label before it.
+    /// Returns None for domain literals, and domains which are a public suffix.
+    ///
+    /// ```rust
+    /// use ::serde_email::Email;
+    ///
+    /// let email = Email::from_str("john@mail.example.co.uk").unwrap();
+    ///
+    /// assert_eq!(email.registrable_domain(), Some("example.co.uk"));
+    /// ```
+    #[cfg(feature = "public-suffix")]
+    pub fn registrable_domain(&self) -> Option<&str> {
+        self.as_email_ref().registrable_domain()
+    }
+
+    pub fn as_str(&self) -> &str {
         &self.raw_email
     }
+
+    /// Returns the part of the email before the `@`.
+    ///
+    /// Quoted local parts are returned with their quotes,
+    /// i.e. for `"john@home"@example.com` this returns `"john@home"`.
+    pub fn local_part(&self) -> &str {
+        self.as_email_ref().local_part()
+    }
+
+    /// Returns the part of the email after the `@`.
+    ///
+    /// Domain literals are returned with their brackets,
+    /// i.e. for `john@[192.168.0.1]` this returns `[192.168.0.1]`.
+    pub fn domain(&self) -> &str {
+        self.as_email_ref().domain()
+    }
 }
 
 /// This is a common default, provided in use for stuff like tests.
@@ -84,7 +451,7 @@ impl Email {
 /// The default email is `default@example.com`.
 impl Default for Email {
     fn default() -> Self {
-        Self::from_str("default@example.com").expect("Default Email should always be valid")
+        Self::from_static_unchecked("default@example.com")
     }
 }
 
@@ -94,9 +461,18 @@ impl Display for Email {
     }
 }
 
+impl<'a> From<EmailRef<'a>> for Email {
+    fn from(email: EmailRef<'a>) -> Self {
+        Self {
+            raw_email: Cow::Owned(email.as_str().to_string()),
+            at_index: email.local_part().len(),
+        }
+    }
+}
+
 impl From<Email> for String {
     fn from(email: Email) -> Self {
-        email.raw_email
+        email.raw_email.into_owned()
     }
 }
 
@@ -106,12 +482,6 @@ impl AsRef<str> for Email {
     }
 }
 
-impl AsRef<String> for Email {
-    fn as_ref(&self) -> &String {
-        &self.raw_email
-    }
-}
-
 impl FromStr for Email {
     type Err = EmailError;
 
@@ -148,6 +518,27 @@ impl PartialEq<String> for Email {
     }
 }
 
+/// Finds the `@` separating the local part from the domain,
+/// skipping over any within a quoted local part.
+const fn find_at_index(raw_email: &str) -> usize {
+    let bytes = raw_email.as_bytes();
+    let mut is_quoted = false;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' if is_quoted => i += 1,
+            b'"' => is_quoted = !is_quoted,
+            b'@' if !is_quoted => return i,
+            _ => {}
+        }
+
+        i += 1;
+    }
+
+    panic!("Email::from_static_unchecked was given an email without an '@'");
+}
+
 #[cfg(test)]
 mod test_from_string {
     use super::*;
@@ -186,6 +577,64 @@ mod test_from_string {
 
         assert!(maybe_email.is_err());
     }
+
+    #[test]
+    fn it_should_not_accept_a_display_name() {
+        let maybe_email = Email::from_string("John <john@example.com>".to_string());
+
+        assert!(maybe_email.is_err());
+    }
+}
+
+#[cfg(test)]
+mod test_from_string_errors {
+    use super::*;
+    use crate::EmailErrorKind;
+
+    #[test]
+    fn it_should_return_the_kind_of_error() {
+        let err = Email::from_string("foxes".to_string()).unwrap_err();
+
+        assert_eq!(err.kind(), &EmailErrorKind::MissingSeparator);
+    }
+
+    #[test]
+    fn it_should_return_the_position_of_the_error() {
+        let err = Email::from_string("john@exam ple.com".to_string()).unwrap_err();
+
+        assert_eq!(err.kind().position(), Some(9));
+    }
+
+    #[test]
+    fn it_should_describe_the_error() {
+        let err = Email::from_string("john@exam ple.com".to_string()).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "invalid email address, was given 'john@exam ple.com', invalid character ' ' at byte 9"
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_from_string_with {
+    use super::*;
+
+    #[test]
+    fn it_should_accept_emails_meeting_the_policy() {
+        let policy = ValidationPolicy::new().require_tld(true);
+        let maybe_email = Email::from_string_with("john@example.com".to_string(), policy);
+
+        assert!(maybe_email.is_ok());
+    }
+
+    #[test]
+    fn it_should_not_accept_emails_breaking_the_policy() {
+        let policy = ValidationPolicy::new().require_tld(true);
+        let maybe_email = Email::from_string_with("john@localhost".to_string(), policy);
+
+        assert!(maybe_email.is_err());
+    }
 }
 
 #[cfg(test)]
@@ -281,13 +730,47 @@ mod test_default {
     fn it_should_create_a_valid_default() {
         let email = Email::default();
 
-        assert!(is_valid_email(&email));
+        assert!(crate::is_valid_email(&email));
+    }
+}
+
+#[cfg(test)]
+mod test_from_static_unchecked {
+    use super::*;
+
+    static NOREPLY: Email = Email::from_static_unchecked("noreply@example.com");
+
+    #[test]
+    fn it_should_build_static_emails() {
+        assert_eq!(NOREPLY, "noreply@example.com");
+        assert_eq!(NOREPLY.local_part(), "noreply");
+        assert_eq!(NOREPLY.domain(), "example.com");
+    }
+
+    #[test]
+    fn it_should_equal_the_same_email_parsed() {
+        assert_eq!(NOREPLY, Email::from_str("noreply@example.com").unwrap());
+    }
+
+    #[test]
+    fn it_should_skip_at_signs_in_quoted_local_parts() {
+        const EMAIL: Email = Email::from_static_unchecked(r#""john@\"home"@example.com"#);
+
+        assert_eq!(EMAIL.local_part(), r#""john@\"home""#);
+        assert_eq!(EMAIL.domain(), "example.com");
+    }
+
+    #[test]
+    #[should_panic]
+    fn it_should_panic_without_a_separator() {
+        Email::from_static_unchecked("example.com");
     }
 }
 
 #[cfg(test)]
 mod test_to_lowercase {
     use super::*;
+    use crate::is_valid_email;
 
     #[test]
     fn it_should_make_it_lowercase() {
@@ -299,6 +782,22 @@ mod test_to_lowercase {
         );
     }
 
+    #[test]
+    fn it_should_lowercase_unicode() {
+        let email: Email = "JÖHN@BÜCHER.DE".parse().unwrap();
+
+        assert_eq!(email.to_lowercase(), "jöhn@bücher.de");
+    }
+
+    #[test]
+    fn it_should_only_lowercase_ascii_if_the_email_would_become_too_long() {
+        let email = Email::from_string(format!("JOHN{}@example.com", "Ⱥ".repeat(30))).unwrap();
+        let lowercase = email.to_lowercase();
+
+        assert_eq!(lowercase.local_part(), format!("john{}", "Ⱥ".repeat(30)));
+        assert!(is_valid_email(lowercase.as_str()));
+    }
+
     #[test]
     fn it_should_not_change_already_lowercase() {
         let email: Email = "joe@example.com".parse().unwrap();
@@ -335,6 +834,350 @@ mod test_to_uppercase {
     }
 }
 
+#[cfg(test)]
+mod test_requires_smtputf8 {
+    use super::*;
+
+    #[test]
+    fn it_should_not_require_smtputf8_for_ascii() {
+        let email: Email = "john@example.com".parse().unwrap();
+
+        assert!(!email.requires_smtputf8());
+    }
+
+    #[test]
+    fn it_should_require_smtputf8_for_chinese_local_parts() {
+        let email: Email = "用户@例子.广告".parse().unwrap();
+
+        assert!(email.requires_smtputf8());
+    }
+
+    #[test]
+    fn it_should_require_smtputf8_for_cyrillic_local_parts() {
+        let email: Email = "почта@example.com".parse().unwrap();
+
+        assert!(email.requires_smtputf8());
+    }
+
+    #[test]
+    fn it_should_require_smtputf8_for_emoji_local_parts() {
+        let email: Email = "🦊@example.com".parse().unwrap();
+
+        assert!(email.requires_smtputf8());
+    }
+
+    #[test]
+    fn it_should_not_require_smtputf8_for_only_unicode_domains() {
+        let email: Email = "john@пример.рф".parse().unwrap();
+
+        assert!(!email.requires_smtputf8());
+    }
+}
+
+#[cfg(test)]
+mod test_requires_idna {
+    use super::*;
+
+    #[test]
+    fn it_should_not_require_idna_for_ascii_domains() {
+        let email: Email = "почта@example.com".parse().unwrap();
+
+        assert!(!email.requires_idna());
+    }
+
+    #[test]
+    fn it_should_require_idna_for_unicode_domains() {
+        let email: Email = "john@пример.рф".parse().unwrap();
+
+        assert!(email.requires_idna());
+    }
+}
+
+#[cfg(test)]
+mod test_to_ascii_domain {
+    use super::*;
+    use crate::EmailErrorKind;
+
+    #[test]
+    fn it_should_convert_the_domain_to_punycode() {
+        let email: Email = "user@bücher.de".parse().unwrap();
+
+        assert_eq!(email.to_ascii_domain().unwrap(), "user@xn--bcher-kva.de");
+    }
+
+    #[test]
+    fn it_should_not_change_the_local_part() {
+        let email: Email = "Jöhn@Bücher.de".parse().unwrap();
+
+        assert_eq!(email.to_ascii_domain().unwrap(), "Jöhn@xn--bcher-kva.de");
+    }
+
+    #[test]
+    fn it_should_keep_ascii_domains() {
+        let email: Email = "user@example.com".parse().unwrap();
+
+        assert_eq!(email.to_ascii_domain().unwrap(), email);
+    }
+
+    #[test]
+    fn it_should_keep_domain_literals() {
+        let email: Email = "user@[192.168.0.1]".parse().unwrap();
+
+        assert_eq!(email.to_ascii_domain().unwrap(), email);
+    }
+
+    #[test]
+    fn it_should_return_an_error_for_invalid_labels() {
+        let email: Email = "user@xn--a.com".parse().unwrap();
+        let err = email.to_ascii_domain().unwrap_err();
+
+        assert_eq!(
+            err.kind(),
+            &EmailErrorKind::InvalidIdnaLabel { position: 5 }
+        );
+    }
+
+    #[test]
+    fn it_should_validate_with_the_policy_given() {
+        let policy = ValidationPolicy::new().max_local_part_length(100);
+        let raw_email = format!("{}@bücher.de", "a".repeat(80));
+        let email = Email::from_string_with(raw_email, policy).unwrap();
+
+        assert!(email.to_ascii_domain().is_err());
+        assert_eq!(
+            email.to_ascii_domain_with(policy).unwrap().domain(),
+            "xn--bcher-kva.de"
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_to_unicode_domain {
+    use super::*;
+    use crate::EmailErrorKind;
+
+    #[test]
+    fn it_should_convert_the_domain_from_punycode() {
+        let email: Email = "user@xn--bcher-kva.de".parse().unwrap();
+
+        assert_eq!(email.to_unicode_domain().unwrap(), "user@bücher.de");
+    }
+
+    #[test]
+    fn it_should_round_trip_with_to_ascii_domain() {
+        let email: Email = "user@bücher.de".parse().unwrap();
+        let round_tripped = email
+            .to_ascii_domain()
+            .unwrap()
+            .to_unicode_domain()
+            .unwrap();
+
+        assert_eq!(round_tripped, email);
+    }
+
+    #[test]
+    fn it_should_split_the_local_part_correctly() {
+        let email: Email = r#""a@b"@xn--bcher-kva.de"#.parse().unwrap();
+        let unicode = email.to_unicode_domain().unwrap();
+
+        assert_eq!(unicode.local_part(), r#""a@b""#);
+        assert_eq!(unicode.domain(), "bücher.de");
+    }
+
+    #[test]
+    fn it_should_return_an_error_for_invalid_labels() {
+        let email: Email = "user@example.xn--a".parse().unwrap();
+        let err = email.to_unicode_domain().unwrap_err();
+
+        assert_eq!(
+            err.kind(),
+            &EmailErrorKind::InvalidIdnaLabel { position: 13 }
+        );
+    }
+
+    #[test]
+    fn it_should_validate_with_the_policy_given() {
+        let policy = ValidationPolicy::new().max_local_part_length(100);
+        let raw_email = format!("{}@xn--bcher-kva.de", "a".repeat(80));
+        let email = Email::from_string_with(raw_email, policy).unwrap();
+
+        assert!(email.to_unicode_domain().is_err());
+        assert_eq!(
+            email.to_unicode_domain_with(policy).unwrap().domain(),
+            "bücher.de"
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_subaddress {
+    use super::*;
+
+    #[test]
+    fn it_should_return_the_subaddress() {
+        let email: Email = "john+news@example.com".parse().unwrap();
+
+        assert_eq!(email.subaddress(), Some("news"));
+    }
+
+    #[test]
+    fn it_should_return_none_without_a_subaddress() {
+        let email: Email = "john@example.com".parse().unwrap();
+
+        assert_eq!(email.subaddress(), None);
+    }
+
+    #[test]
+    fn it_should_use_the_separator_given() {
+        let email: Email = "john-news@example.com".parse().unwrap();
+
+        assert_eq!(email.subaddress(), None);
+        assert_eq!(email.subaddress_separated_by('-'), Some("news"));
+    }
+}
+
+#[cfg(test)]
+mod test_without_subaddress {
+    use super::*;
+
+    #[test]
+    fn it_should_remove_the_subaddress() {
+        let email: Email = "john+news@example.com".parse().unwrap();
+
+        assert_eq!(email.without_subaddress(), "john@example.com");
+    }
+
+    #[test]
+    fn it_should_keep_emails_without_a_subaddress() {
+        let email: Email = "john@example.com".parse().unwrap();
+
+        assert_eq!(email.without_subaddress(), email);
+    }
+
+    #[test]
+    fn it_should_use_the_separator_given() {
+        let email: Email = "john-news@example.com".parse().unwrap();
+
+        assert_eq!(
+            email.without_subaddress_separated_by('-'),
+            "john@example.com"
+        );
+    }
+
+    #[test]
+    fn it_should_split_the_local_part_correctly() {
+        let email: Email = "john+news@example.com".parse().unwrap();
+        let without = email.without_subaddress();
+
+        assert_eq!(without.local_part(), "john");
+        assert_eq!(without.domain(), "example.com");
+    }
+}
+
+#[cfg(test)]
+mod test_with_subaddress {
+    use super::*;
+    use crate::EmailErrorKind;
+    use crate::ValidationMode;
+
+    #[test]
+    fn it_should_add_a_subaddress() {
+        let email: Email = "john@example.com".parse().unwrap();
+
+        assert_eq!(
+            email.with_subaddress("news").unwrap(),
+            "john+news@example.com"
+        );
+    }
+
+    #[test]
+    fn it_should_replace_an_existing_subaddress() {
+        let email: Email = "john+news@example.com".parse().unwrap();
+
+        assert_eq!(
+            email.with_subaddress("shop").unwrap(),
+            "john+shop@example.com"
+        );
+    }
+
+    #[test]
+    fn it_should_use_the_separator_given() {
+        let email: Email = "john-news@example.com".parse().unwrap();
+
+        assert_eq!(
+            email.with_subaddress_separated_by("shop", '-').unwrap(),
+            "john-shop@example.com"
+        );
+    }
+
+    #[test]
+    fn it_should_validate_with_the_policy_given() {
+        let email = Email::from_str_with(".John@Example.com", ValidationMode::Html5).unwrap();
+
+        assert!(email.with_subaddress("x").is_err());
+        assert_eq!(
+            email
+                .with_subaddress_with("x", ValidationMode::Html5)
+                .unwrap(),
+            ".John+x@Example.com"
+        );
+    }
+
+    #[test]
+    fn it_should_reject_subaddresses_making_the_email_invalid() {
+        let email: Email = "john@example.com".parse().unwrap();
+        let err = email.with_subaddress("big news").unwrap_err();
+
+        assert_eq!(
+            err.kind(),
+            &EmailErrorKind::InvalidCharacter {
+                character: ' ',
+                position: 8
+            }
+        );
+    }
+
+    #[test]
+    fn it_should_reject_subaddresses_making_the_local_part_too_long() {
+        let email: Email = "john@example.com".parse().unwrap();
+        let maybe_email = email.with_subaddress("a".repeat(60));
+
+        assert!(maybe_email.is_err());
+    }
+
+    #[test]
+    fn it_should_reject_subaddresses_on_quoted_local_parts() {
+        let email: Email = r#""john doe"@example.com"#.parse().unwrap();
+        let maybe_email = email.with_subaddress("news");
+
+        assert!(maybe_email.is_err());
+    }
+}
+
+#[cfg(test)]
+mod test_canonical_for_provider {
+    use super::*;
+
+    #[test]
+    fn it_should_give_the_same_key_for_the_same_gmail_inbox() {
+        let a: Email = "j.o.h.n+1@gmail.com".parse().unwrap();
+        let b: Email = "john@googlemail.com".parse().unwrap();
+
+        assert_eq!(
+            a.canonical_for_provider().unwrap(),
+            b.canonical_for_provider().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_should_not_alter_the_original_email() {
+        let email: Email = "j.o.h.n+1@gmail.com".parse().unwrap();
+        let _ = email.canonical_for_provider();
+
+        assert_eq!(email, "j.o.h.n+1@gmail.com");
+    }
+}
+
 #[cfg(test)]
 mod test_partial_eq {
     use super::*;
@@ -355,3 +1198,163 @@ mod test_partial_eq {
         assert!(is_not_equal);
     }
 }
+
+#[cfg(test)]
+mod test_domain_kind {
+    use super::*;
+    use ::core::net::Ipv4Addr;
+    use ::core::net::Ipv6Addr;
+
+    #[test]
+    fn it_should_return_name_for_domain_names() {
+        let email = Email::from_str("john@example.com").unwrap();
+
+        assert_eq!(email.domain_kind(), Domain::Name);
+    }
+
+    #[test]
+    fn it_should_return_ip_addresses_for_address_literals() {
+        let ipv4 = Email::from_str("john@[192.168.0.1]").unwrap();
+        let ipv6 = Email::from_str("john@[IPv6:::1]").unwrap();
+
+        assert_eq!(
+            ipv4.domain_kind(),
+            Domain::Ipv4(Ipv4Addr::new(192, 168, 0, 1))
+        );
+        assert_eq!(ipv6.domain_kind(), Domain::Ipv6(Ipv6Addr::LOCALHOST));
+    }
+
+    #[test]
+    fn it_should_return_general_for_other_literals() {
+        let email = Email::from_str("john@[tag:content]").unwrap();
+
+        assert_eq!(email.domain_kind(), Domain::General);
+    }
+}
+
+#[cfg(all(test, feature = "disposable"))]
+mod test_is_disposable {
+    use super::*;
+
+    #[test]
+    fn it_should_return_true_for_disposable_domains() {
+        let email = Email::from_str("john@mailinator.com").unwrap();
+
+        assert!(email.is_disposable());
+    }
+
+    #[test]
+    fn it_should_return_true_for_subdomains_of_disposable_domains() {
+        let email = Email::from_str("john@inbox.Mailinator.com").unwrap();
+
+        assert!(email.is_disposable());
+    }
+
+    #[test]
+    fn it_should_return_false_for_other_domains() {
+        let email = Email::from_str("john@example.com").unwrap();
+
+        assert!(!email.is_disposable());
+    }
+}
+
+#[cfg(all(test, feature = "public-suffix"))]
+mod test_public_suffix {
+    use super::*;
+
+    #[test]
+    fn it_should_return_the_public_suffix() {
+        let email = Email::from_str("john@mail.corp.example.co.uk").unwrap();
+
+        assert_eq!(email.public_suffix(), Some("co.uk"));
+    }
+
+    #[test]
+    fn it_should_return_the_registrable_domain() {
+        let email = Email::from_str("john@mail.corp.example.co.uk").unwrap();
+
+        assert_eq!(email.registrable_domain(), Some("example.co.uk"));
+    }
+
+    #[test]
+    fn it_should_return_none_for_domain_literals() {
+        let email = Email::from_str("john@[192.168.0.1]").unwrap();
+
+        assert_eq!(email.public_suffix(), None);
+        assert_eq!(email.registrable_domain(), None);
+    }
+
+    #[test]
+    fn it_should_return_no_registrable_domain_for_public_suffixes() {
+        let email = Email::from_str("john@co.uk").unwrap();
+
+        assert_eq!(email.public_suffix(), Some("co.uk"));
+        assert_eq!(email.registrable_domain(), None);
+    }
+}
+
+#[cfg(test)]
+mod test_local_part {
+    use super::*;
+
+    #[test]
+    fn it_should_return_the_part_before_the_at() {
+        let email: Email = "john@example.com".parse().unwrap();
+
+        assert_eq!(email.local_part(), "john");
+    }
+
+    #[test]
+    fn it_should_return_quoted_local_parts_with_quotes() {
+        let email: Email = r#""john@home"@example.com"#.parse().unwrap();
+
+        assert_eq!(email.local_part(), r#""john@home""#);
+    }
+
+    #[test]
+    fn it_should_return_quoted_local_parts_with_spaces() {
+        let email: Email = r#""john doe"@example.com"#.parse().unwrap();
+
+        assert_eq!(email.local_part(), r#""john doe""#);
+    }
+
+    #[test]
+    fn it_should_keep_the_split_after_lowercasing() {
+        let email: Email = r#""JoHn@HoMe"@eXaMpLe.com"#.parse().unwrap();
+
+        assert_eq!(email.to_lowercase().local_part(), r#""john@home""#);
+    }
+}
+
+#[cfg(test)]
+mod test_domain {
+    use super::*;
+
+    #[test]
+    fn it_should_return_the_part_after_the_at() {
+        let email: Email = "john@example.com".parse().unwrap();
+
+        assert_eq!(email.domain(), "example.com");
+    }
+
+    #[test]
+    fn it_should_return_the_domain_for_quoted_local_parts() {
+        let email: Email = r#""john@home"@example.com"#.parse().unwrap();
+
+        assert_eq!(email.domain(), "example.com");
+    }
+
+    #[test]
+    fn it_should_return_domain_literals_with_brackets() {
+        let email: Email = "john@[192.168.0.1]".parse().unwrap();
+
+        assert_eq!(email.domain(), "[192.168.0.1]");
+    }
+
+    #[test]
+    fn it_should_keep_the_split_after_uppercasing() {
+        let email: Email = r#""john@home"@example.com"#.parse().unwrap();
+
+        assert_eq!(email.to_uppercase().domain(), "EXAMPLE.COM");
+    }
+}
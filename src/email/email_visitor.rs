@@ -1,7 +1,9 @@
+use ::alloc::format;
+use ::alloc::string::String;
+use ::core::fmt::Formatter;
+use ::core::fmt::Result as FmtResult;
 use ::serde::de::Error as SerdeDeError;
 use ::serde::de::Visitor;
-use ::std::fmt::Formatter;
-use ::std::fmt::Result as FmtResult;
 
 use crate::Email;
 
@@ -18,19 +20,19 @@ impl<'de> Visitor<'de> for EmailVisitor {
     where
         E: SerdeDeError,
     {
-        return Email::from_str(raw_email).map_err(|err| {
+        Email::from_str(raw_email).map_err(|err| {
             let msg = format!("{}", err);
             SerdeDeError::custom(msg)
-        });
+        })
     }
 
     fn visit_string<E>(self, raw_email: String) -> Result<Self::Value, E>
     where
         E: SerdeDeError,
     {
-        return Email::from_string(raw_email).map_err(|err| {
+        Email::from_string(raw_email).map_err(|err| {
             let msg = format!("{}", err);
             SerdeDeError::custom(msg)
-        });
+        })
     }
 }
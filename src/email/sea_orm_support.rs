@@ -1,3 +1,4 @@
+use ::core::convert::From;
 use ::sea_orm::entity::ActiveValue;
 use ::sea_orm::entity::IntoActiveValue;
 use ::sea_orm::error::DbErr;
@@ -11,7 +12,6 @@ use ::sea_orm::QueryResult;
 use ::sea_orm::TryGetError;
 use ::sea_orm::TryGetable;
 use ::sea_orm::Value;
-use ::std::convert::From;
 
 use crate::Email;
 
@@ -33,7 +33,7 @@ impl TryGetable for Email {
         I: ColIdx,
     {
         res.try_get_by::<Option<String>, I>(index)
-            .map_err(|db_err| TryGetError::DbErr(db_err))
+            .map_err(TryGetError::DbErr)
             .and_then(|maybe_raw| match maybe_raw {
                 Some(raw) => Email::from_string(raw).map_err(|err| {
                     let db_err = DbErr::Custom(err.to_string());
@@ -45,7 +45,7 @@ impl TryGetable for Email {
 
     fn try_get(res: &QueryResult, pre: &str, col: &str) -> Result<Self, TryGetError> {
         res.try_get::<Option<String>>(pre, col)
-            .map_err(|db_err| TryGetError::DbErr(db_err))
+            .map_err(TryGetError::DbErr)
             .and_then(|maybe_raw| match maybe_raw {
                 Some(raw) => Email::from_string(raw).map_err(|err| {
                     let db_err = DbErr::Custom(err.to_string());
@@ -44,6 +44,17 @@ mod test_serialisation {
 
         assert_eq!(raw, r#""john@example.com""#);
     }
+
+    #[test]
+    fn it_should_serialise_structs_with_email() {
+        let person = Person {
+            name: "John Doe".to_string(),
+            email: Email::from_str("john@example.com").unwrap(),
+        };
+        let raw = serde_json::to_string(&person).unwrap();
+
+        assert_eq!(raw, r#"{"name":"John Doe","email":"john@example.com"}"#);
+    }
 }
 
 #[cfg(test)]
@@ -81,6 +92,15 @@ mod test_deserialisation {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn it_should_explain_why_an_email_did_not_deserialise() {
+        let raw_json_email = r#""john@exam ple.com""#;
+
+        let err = serde_json::from_str::<Email>(raw_json_email).unwrap_err();
+
+        assert!(err.to_string().contains("invalid character ' ' at byte 9"));
+    }
+
     #[test]
     fn it_should_deserialise_email_from_string() {
         let raw_json_email = r#""john@example.com""#;
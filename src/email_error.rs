@@ -1,20 +1,39 @@
-use ::std::error::Error;
-use ::std::fmt::Display;
-use ::std::fmt::Formatter;
-use ::std::fmt::Result as FmtResult;
+use ::alloc::string::String;
+use ::core::fmt::Display;
+use ::core::fmt::Formatter;
+use ::core::fmt::Result as FmtResult;
+
+use crate::EmailErrorKind;
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum EmailError {
-    Invalid { raw_email: String },
+    Invalid {
+        raw_email: String,
+        kind: EmailErrorKind,
+    },
+}
+
+impl EmailError {
+    /// Returns the reason the email address was rejected.
+    pub fn kind(&self) -> &EmailErrorKind {
+        match self {
+            EmailError::Invalid { kind, .. } => kind,
+        }
+    }
 }
 
-impl Error for EmailError {}
+#[cfg(feature = "std")]
+impl ::std::error::Error for EmailError {}
 
 impl Display for EmailError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match self {
-            EmailError::Invalid { raw_email } => {
-                write!(f, "invalid email address, was given '{}'", raw_email)
+            EmailError::Invalid { raw_email, kind } => {
+                write!(
+                    f,
+                    "invalid email address, was given '{}', {}",
+                    raw_email, kind
+                )
             }
         }
     }
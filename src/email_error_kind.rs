@@ -0,0 +1,316 @@
+use ::core::fmt::Display;
+use ::core::fmt::Formatter;
+use ::core::fmt::Result as FmtResult;
+
+/// Describes why an email address failed validation.
+///
+/// Positions are byte offsets into the address that was given,
+/// which can be used to point to the problem within a form field.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EmailErrorKind {
+    /// There is no `@` separating the local part from the domain.
+    MissingSeparator,
+
+    /// There is nothing before the `@`.
+    LocalPartEmpty,
+
+    /// The local part is longer than the maximum allowed.
+    LocalPartTooLong { length: usize, max: usize },
+
+    /// There is nothing after the `@`.
+    DomainEmpty,
+
+    /// The domain is longer than the maximum allowed.
+    DomainTooLong { length: usize, max: usize },
+
+    /// The whole address is longer than the maximum allowed.
+    AddressTooLong { length: usize, max: usize },
+
+    /// A character was found which is not allowed where it appears.
+    InvalidCharacter { character: char, position: usize },
+
+    /// A label within the domain (the text between dots) is empty,
+    /// too long, or starts or ends with a character which isn't a letter or digit.
+    InvalidLabel { position: usize },
+
+    /// A quoted local part was opened, but never closed.
+    UnbalancedQuotes { position: usize },
+
+    /// A domain literal was opened with a `[`, but never closed.
+    UnbalancedBrackets { position: usize },
+
+    /// The local part is quoted, and the `ValidationPolicy` does not allow that.
+    QuotedLocalPartNotAllowed,
+
+    /// The domain is a literal, such as `[192.168.0.1]`,
+    /// and the `ValidationPolicy` does not allow that.
+    DomainLiteralNotAllowed,
+
+    /// The domain has a single label, such as `localhost`,
+    /// and the `ValidationPolicy` does not allow that.
+    SingleLabelDomain,
+
+    /// The domain does not end with a top level domain,
+    /// and the `ValidationPolicy` requires one.
+    MissingTld,
+
+    /// The top level domain is not in the Public Suffix List,
+    /// and the `ValidationPolicy` requires a known one.
+    UnknownTld { position: usize },
+
+    /// The domain belongs to a disposable email service,
+    /// and the `ValidationPolicy` does not allow those.
+    DisposableDomain { position: usize },
+
+    /// A label within the domain cannot be converted using IDNA.
+    InvalidIdnaLabel { position: usize },
+
+    /// A domain literal does not hold an address the grammar allows,
+    /// such as `[300.0.0.1]` with RFC 5321.
+    InvalidDomainLiteral { position: usize },
+
+    /// A domain literal looks like an IPv4 address, such as `[300.0.0.1]`,
+    /// but is not a valid one.
+    InvalidIpv4Literal { position: usize },
+
+    /// A domain literal starts with `IPv6:`, but does not hold a valid IPv6 address.
+    InvalidIpv6Literal { position: usize },
+
+    /// A domain literal is a private IP address, such as `[192.168.0.1]`,
+    /// and the `ValidationPolicy` does not allow that.
+    PrivateDomainLiteral { position: usize },
+
+    /// A comment was opened with a `(`, but never closed.
+    UnbalancedParentheses { position: usize },
+
+    /// An address was opened with a `<`, but never closed.
+    UnbalancedAngleBrackets { position: usize },
+}
+
+impl EmailErrorKind {
+    /// Returns the byte offset of the problem, if it has one.
+    pub fn position(&self) -> Option<usize> {
+        match *self {
+            EmailErrorKind::InvalidCharacter { position, .. } => Some(position),
+            EmailErrorKind::InvalidLabel { position } => Some(position),
+            EmailErrorKind::UnbalancedQuotes { position } => Some(position),
+            EmailErrorKind::UnbalancedBrackets { position } => Some(position),
+            EmailErrorKind::UnknownTld { position } => Some(position),
+            EmailErrorKind::DisposableDomain { position } => Some(position),
+            EmailErrorKind::InvalidIdnaLabel { position } => Some(position),
+            EmailErrorKind::InvalidDomainLiteral { position } => Some(position),
+            EmailErrorKind::InvalidIpv4Literal { position } => Some(position),
+            EmailErrorKind::InvalidIpv6Literal { position } => Some(position),
+            EmailErrorKind::PrivateDomainLiteral { position } => Some(position),
+            EmailErrorKind::UnbalancedParentheses { position } => Some(position),
+            EmailErrorKind::UnbalancedAngleBrackets { position } => Some(position),
+            _ => None,
+        }
+    }
+
+    /// Returns how many bytes over its limit the address, local part, or domain is,
+    /// if it is too long.
+    pub fn bytes_over_limit(&self) -> Option<usize> {
+        match *self {
+            EmailErrorKind::LocalPartTooLong { length, max } => Some(length - max),
+            EmailErrorKind::DomainTooLong { length, max } => Some(length - max),
+            EmailErrorKind::AddressTooLong { length, max } => Some(length - max),
+            _ => None,
+        }
+    }
+
+    /// Moves the position of the problem along by the offset given.
+    ///
+    /// This is for when an address is validated on its own,
+    /// and the problem needs reporting relative to the text it came from.
+    pub(crate) fn offset_by(self, offset: usize) -> Self {
+        match self {
+            EmailErrorKind::InvalidCharacter {
+                character,
+                position,
+            } => EmailErrorKind::InvalidCharacter {
+                character,
+                position: position + offset,
+            },
+            EmailErrorKind::InvalidLabel { position } => EmailErrorKind::InvalidLabel {
+                position: position + offset,
+            },
+            EmailErrorKind::UnbalancedQuotes { position } => EmailErrorKind::UnbalancedQuotes {
+                position: position + offset,
+            },
+            EmailErrorKind::UnbalancedBrackets { position } => EmailErrorKind::UnbalancedBrackets {
+                position: position + offset,
+            },
+            EmailErrorKind::UnknownTld { position } => EmailErrorKind::UnknownTld {
+                position: position + offset,
+            },
+            EmailErrorKind::DisposableDomain { position } => EmailErrorKind::DisposableDomain {
+                position: position + offset,
+            },
+            EmailErrorKind::InvalidIdnaLabel { position } => EmailErrorKind::InvalidIdnaLabel {
+                position: position + offset,
+            },
+            EmailErrorKind::InvalidDomainLiteral { position } => {
+                EmailErrorKind::InvalidDomainLiteral {
+                    position: position + offset,
+                }
+            }
+            EmailErrorKind::InvalidIpv4Literal { position } => EmailErrorKind::InvalidIpv4Literal {
+                position: position + offset,
+            },
+            EmailErrorKind::InvalidIpv6Literal { position } => EmailErrorKind::InvalidIpv6Literal {
+                position: position + offset,
+            },
+            EmailErrorKind::PrivateDomainLiteral { position } => {
+                EmailErrorKind::PrivateDomainLiteral {
+                    position: position + offset,
+                }
+            }
+            EmailErrorKind::UnbalancedParentheses { position } => {
+                EmailErrorKind::UnbalancedParentheses {
+                    position: position + offset,
+                }
+            }
+            EmailErrorKind::UnbalancedAngleBrackets { position } => {
+                EmailErrorKind::UnbalancedAngleBrackets {
+                    position: position + offset,
+                }
+            }
+            kind => kind,
+        }
+    }
+}
+
+impl Display for EmailErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            EmailErrorKind::MissingSeparator => {
+                write!(f, "missing '@' between the local part and domain")
+            }
+            EmailErrorKind::LocalPartEmpty => write!(f, "local part is empty"),
+            EmailErrorKind::LocalPartTooLong { length, max } => {
+                write_too_long(f, "local part", *length, *max)
+            }
+            EmailErrorKind::DomainEmpty => write!(f, "domain is empty"),
+            EmailErrorKind::DomainTooLong { length, max } => {
+                write_too_long(f, "domain", *length, *max)
+            }
+            EmailErrorKind::AddressTooLong { length, max } => {
+                write_too_long(f, "address", *length, *max)
+            }
+            EmailErrorKind::InvalidCharacter {
+                character,
+                position,
+            } => {
+                write!(f, "invalid character {character:?} at byte {position}")
+            }
+            EmailErrorKind::InvalidLabel { position } => {
+                write!(f, "invalid domain label at byte {position}")
+            }
+            EmailErrorKind::UnbalancedQuotes { position } => {
+                write!(f, "quote opened at byte {position} is never closed")
+            }
+            EmailErrorKind::UnbalancedBrackets { position } => {
+                write!(
+                    f,
+                    "domain literal opened at byte {position} is never closed"
+                )
+            }
+            EmailErrorKind::QuotedLocalPartNotAllowed => {
+                write!(f, "quoted local parts are not allowed")
+            }
+            EmailErrorKind::DomainLiteralNotAllowed => {
+                write!(f, "domain literals are not allowed")
+            }
+            EmailErrorKind::SingleLabelDomain => {
+                write!(f, "domain must have more than one label")
+            }
+            EmailErrorKind::MissingTld => write!(f, "domain is missing a top level domain"),
+            EmailErrorKind::UnknownTld { position } => {
+                write!(f, "top level domain at byte {position} is not a known one")
+            }
+            EmailErrorKind::DisposableDomain { position } => {
+                write!(f, "domain at byte {position} is a disposable email domain")
+            }
+            EmailErrorKind::InvalidIdnaLabel { position } => {
+                write!(f, "domain label at byte {position} is not valid IDNA")
+            }
+            EmailErrorKind::InvalidDomainLiteral { position } => {
+                write!(
+                    f,
+                    "domain literal at byte {position} is not a valid address"
+                )
+            }
+            EmailErrorKind::InvalidIpv4Literal { position } => {
+                write!(
+                    f,
+                    "domain literal at byte {position} is not a valid IPv4 address"
+                )
+            }
+            EmailErrorKind::InvalidIpv6Literal { position } => {
+                write!(
+                    f,
+                    "domain literal at byte {position} is not a valid IPv6 address"
+                )
+            }
+            EmailErrorKind::PrivateDomainLiteral { position } => {
+                write!(
+                    f,
+                    "domain literal at byte {position} is a private IP address"
+                )
+            }
+            EmailErrorKind::UnbalancedParentheses { position } => {
+                write!(f, "comment opened at byte {position} is never closed")
+            }
+            EmailErrorKind::UnbalancedAngleBrackets { position } => {
+                write!(f, "address opened at byte {position} is never closed")
+            }
+        }
+    }
+}
+
+fn write_too_long(f: &mut Formatter<'_>, part: &str, length: usize, max: usize) -> FmtResult {
+    write!(
+        f,
+        "{part} is {length} bytes long, {} bytes over the maximum of {max}",
+        length - max
+    )
+}
+
+#[cfg(test)]
+mod test_bytes_over_limit {
+    use super::*;
+
+    #[test]
+    fn it_should_return_the_bytes_over_the_limit() {
+        let kind = EmailErrorKind::AddressTooLong {
+            length: 300,
+            max: 254,
+        };
+
+        assert_eq!(kind.bytes_over_limit(), Some(46));
+    }
+
+    #[test]
+    fn it_should_return_none_for_other_errors() {
+        assert_eq!(EmailErrorKind::DomainEmpty.bytes_over_limit(), None);
+    }
+}
+
+#[cfg(test)]
+mod test_display {
+    use super::*;
+
+    #[test]
+    fn it_should_name_the_limit_and_the_excess() {
+        let kind = EmailErrorKind::LocalPartTooLong {
+            length: 70,
+            max: 64,
+        };
+
+        assert_eq!(
+            kind.to_string(),
+            "local part is 70 bytes long, 6 bytes over the maximum of 64"
+        );
+    }
+}
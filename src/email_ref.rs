@@ -0,0 +1,432 @@
+use ::alloc::format;
+use ::alloc::string::String;
+use ::alloc::string::ToString;
+use ::core::convert::AsRef;
+use ::core::fmt::Display;
+use ::core::fmt::Formatter;
+use ::core::fmt::Result as FmtResult;
+
+#[cfg(feature = "disposable")]
+use crate::disposable_domains::is_built_in_disposable_domain;
+use crate::idna_domain::domain_to_ascii_at;
+use crate::idna_domain::domain_to_unicode_at;
+use crate::parse_email::parse_email;
+#[cfg(feature = "public-suffix")]
+use crate::public_suffix::find_public_suffix;
+#[cfg(feature = "public-suffix")]
+use crate::public_suffix::find_registrable_domain;
+use crate::split_subaddress::split_subaddress;
+use crate::Domain;
+use crate::Email;
+use crate::EmailError;
+use crate::EmailErrorKind;
+use crate::ValidationPolicy;
+
+#[cfg(feature = "serde")]
+mod email_ref_visitor;
+#[cfg(feature = "serde")]
+pub(crate) use self::email_ref_visitor::*;
+
+#[cfg(feature = "serde")]
+mod serde_support;
+
+/// A validated email, which borrows the text it was created from.
+///
+/// This is for validating lots of emails without allocating for each one,
+/// such as when reading through a large file.
+/// It has the same accessors as `Email`,
+/// and can be turned into one using `EmailRef::to_owned`.
+/// Methods which build a different address, such as `EmailRef::to_ascii_domain`,
+/// return an owned `Email`.
+///
+/// `to_lowercase`, `to_uppercase`, `canonical_for_provider`, and `to_mailto_uri`
+/// are left out, and can be called on the result of `EmailRef::to_owned`.
+///
+/// ```rust
+/// use ::serde_email::EmailRef;
+///
+/// let text = "john@example.com\njane@example.com";
+/// let emails: Vec<EmailRef> = text.lines().filter_map(|line| EmailRef::from_str(line).ok()).collect();
+///
+/// assert_eq!(emails[1].domain(), "example.com");
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct EmailRef<'a> {
+    raw_email: &'a str,
+    at_index: usize,
+}
+
+impl<'a> EmailRef<'a> {
+    /// Creates a new EmailRef, borrowing the `&str` given.
+    ///
+    /// If the given string doesn't look like a valid email,
+    /// then this will return an EmailError describing why.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(raw_email: &'a str) -> Result<Self, EmailError> {
+        Self::from_str_with(raw_email, ValidationPolicy::new())
+    }
+
+    /// Creates a new EmailRef, borrowing the `&str` given,
+    /// validating it using the rules of the policy.
+    pub fn from_str_with<P>(raw_email: &'a str, policy: P) -> Result<Self, EmailError>
+    where
+        P: Into<ValidationPolicy>,
+    {
+        match parse_email(raw_email, &policy.into()) {
+            Ok(at_index) => Ok(Self {
+                raw_email,
+                at_index,
+            }),
+            Err(kind) => Err(EmailError::Invalid {
+                raw_email: raw_email.to_string(),
+                kind,
+            }),
+        }
+    }
+
+    /// Builds an EmailRef for a part of an email which has already been validated.
+    pub(crate) fn from_valid_parts(raw_email: &'a str, at_index: usize) -> Self {
+        Self {
+            raw_email,
+            at_index,
+        }
+    }
+
+    /// Returns an `Email` holding a copy of this email.
+    #[allow(clippy::wrong_self_convention)]
+    pub fn to_owned(&self) -> Email {
+        Email::from(*self)
+    }
+
+    /// Returns if sending to this email needs the SMTPUTF8 extension,
+    /// which is when the local part contains non-ASCII characters.
+    pub fn requires_smtputf8(&self) -> bool {
+        !self.local_part().is_ascii()
+    }
+
+    /// Returns if the domain contains non-ASCII characters,
+    /// and so needs converting with `Email::to_ascii_domain` for systems which only support ASCII.
+    pub fn requires_idna(&self) -> bool {
+        !self.domain().is_ascii()
+    }
+
+    /// Returns the subaddress of this email, using `+` as the separator.
+    /// i.e. for `john+news@example.com` this returns `news`.
+    ///
+    /// Returns None if there is no subaddress, or if the local part is quoted.
+    pub fn subaddress(&self) -> Option<&'a str> {
+        self.subaddress_separated_by('+')
+    }
+
+    /// Returns the subaddress of this email, using the separator given.
+    /// i.e. for `john-news@example.com` with `-`, this returns `news`.
+    pub fn subaddress_separated_by(&self, separator: char) -> Option<&'a str> {
+        split_subaddress(self.local_part(), separator).map(|(_, subaddress)| subaddress)
+    }
+
+    /// Returns an `Email` with the subaddress removed, using `+` as the separator.
+    /// i.e. `john+news@example.com` becomes `john@example.com`.
+    pub fn without_subaddress(&self) -> Email {
+        self.without_subaddress_separated_by('+')
+    }
+
+    /// Returns an `Email` with the subaddress removed, using the separator given.
+    pub fn without_subaddress_separated_by(&self, separator: char) -> Email {
+        match split_subaddress(self.local_part(), separator) {
+            Some((mailbox, _)) => Email::from_valid_parts(mailbox.to_string(), self.domain()),
+            None => self.to_owned(),
+        }
+    }
+
+    /// Returns an `Email` with the subaddress given, using `+` as the separator.
+    /// Any existing subaddress is replaced.
+    ///
+    /// The result is validated with the default policy.
+    /// If the subaddress would make the email invalid,
+    /// then this will return an EmailError describing why.
+    pub fn with_subaddress<S>(&self, subaddress: S) -> Result<Email, EmailError>
+    where
+        S: AsRef<str>,
+    {
+        self.with_subaddress_separated_by(subaddress, '+')
+    }
+
+    /// Returns an `Email` with the subaddress given, using `+` as the separator,
+    /// validating the result using the rules of the policy.
+    pub fn with_subaddress_with<S, P>(&self, subaddress: S, policy: P) -> Result<Email, EmailError>
+    where
+        S: AsRef<str>,
+        P: Into<ValidationPolicy>,
+    {
+        self.with_subaddress_separated_by_with(subaddress, '+', policy)
+    }
+
+    /// Returns an `Email` with the subaddress given, using the separator given.
+    /// Any existing subaddress is replaced.
+    pub fn with_subaddress_separated_by<S>(
+        &self,
+        subaddress: S,
+        separator: char,
+    ) -> Result<Email, EmailError>
+    where
+        S: AsRef<str>,
+    {
+        self.with_subaddress_separated_by_with(subaddress, separator, ValidationPolicy::new())
+    }
+
+    /// Returns an `Email` with the subaddress given, using the separator given,
+    /// validating the result using the rules of the policy.
+    pub fn with_subaddress_separated_by_with<S, P>(
+        &self,
+        subaddress: S,
+        separator: char,
+        policy: P,
+    ) -> Result<Email, EmailError>
+    where
+        S: AsRef<str>,
+        P: Into<ValidationPolicy>,
+    {
+        let mailbox = split_subaddress(self.local_part(), separator)
+            .map(|(mailbox, _)| mailbox)
+            .unwrap_or(self.local_part());
+
+        Email::from_string_with(
+            format!(
+                "{mailbox}{separator}{}@{}",
+                subaddress.as_ref(),
+                self.domain()
+            ),
+            policy,
+        )
+    }
+
+    /// Returns an `Email`, where the domain has been converted to ASCII
+    /// using the UTS #46 IDNA mapping. See `Email::to_ascii_domain`.
+    pub fn to_ascii_domain(&self) -> Result<Email, EmailError> {
+        self.to_ascii_domain_with(ValidationPolicy::new())
+    }
+
+    /// Returns an `Email`, where the domain has been converted to ASCII,
+    /// validating the result using the rules of the policy.
+    pub fn to_ascii_domain_with<P>(&self, policy: P) -> Result<Email, EmailError>
+    where
+        P: Into<ValidationPolicy>,
+    {
+        self.map_domain(domain_to_ascii_at, policy.into())
+    }
+
+    /// Returns an `Email`, where the domain has been converted to Unicode
+    /// using the UTS #46 IDNA mapping. See `Email::to_unicode_domain`.
+    pub fn to_unicode_domain(&self) -> Result<Email, EmailError> {
+        self.to_unicode_domain_with(ValidationPolicy::new())
+    }
+
+    /// Returns an `Email`, where the domain has been converted to Unicode,
+    /// validating the result using the rules of the policy.
+    pub fn to_unicode_domain_with<P>(&self, policy: P) -> Result<Email, EmailError>
+    where
+        P: Into<ValidationPolicy>,
+    {
+        self.map_domain(domain_to_unicode_at, policy.into())
+    }
+
+    fn map_domain<F>(&self, f: F, policy: ValidationPolicy) -> Result<Email, EmailError>
+    where
+        F: FnOnce(&str, usize) -> Result<String, EmailErrorKind>,
+    {
+        let domain = f(self.domain(), self.at_index + 1).map_err(|kind| EmailError::Invalid {
+            raw_email: self.raw_email.to_string(),
+            kind,
+        })?;
+
+        Email::from_string_with(format!("{}@{}", self.local_part(), domain), policy)
+    }
+
+    /// Returns the kind of domain this email has,
+    /// such as a domain name, or an IP address literal.
+    pub fn domain_kind(&self) -> Domain {
+        Domain::of(self.domain())
+    }
+
+    /// Returns true if the domain belongs to a disposable email service,
+    /// using the built in list of `DisposableDomains`.
+    #[cfg(feature = "disposable")]
+    pub fn is_disposable(&self) -> bool {
+        is_built_in_disposable_domain(self.domain())
+    }
+
+    /// Returns the public suffix of the domain, using the Public Suffix List.
+    /// i.e. `co.uk` for `john@mail.example.co.uk`.
+    ///
+    /// Returns None for domain literals.
+    #[cfg(feature = "public-suffix")]
+    pub fn public_suffix(&self) -> Option<&'a str> {
+        let domain = self.domain();
+        find_public_suffix(domain).map(|index| &domain[index..])
+    }
+
+    /// Returns the part of the domain that can be registered,
+    /// which is the public suffix with one label before it.
+    /// i.e. `example.co.uk` for `john@mail.example.co.uk`.
+    ///
+    /// Returns None for domain literals, and domains which are a public suffix.
+    #[cfg(feature = "public-suffix")]
+    pub fn registrable_domain(&self) -> Option<&'a str> {
+        let domain = self.domain();
+        find_registrable_domain(domain).map(|index| &domain[index..])
+    }
+
+    pub fn as_str(&self) -> &'a str {
+        self.raw_email
+    }
+
+    /// Returns the part of the email before the `@`.
+    ///
+    /// Quoted local parts are returned with their quotes,
+    /// i.e. for `"john@home"@example.com` this returns `"john@home"`.
+    pub fn local_part(&self) -> &'a str {
+        &self.raw_email[..self.at_index]
+    }
+
+    /// Returns the part of the email after the `@`.
+    ///
+    /// Domain literals are returned with their brackets,
+    /// i.e. for `john@[192.168.0.1]` this returns `[192.168.0.1]`.
+    pub fn domain(&self) -> &'a str {
+        &self.raw_email[self.at_index + 1..]
+    }
+}
+
+impl Display for EmailRef<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.raw_email)
+    }
+}
+
+impl AsRef<str> for EmailRef<'_> {
+    fn as_ref(&self) -> &str {
+        self.raw_email
+    }
+}
+
+impl<'a> TryFrom<&'a str> for EmailRef<'a> {
+    type Error = EmailError;
+
+    fn try_from(raw: &'a str) -> Result<Self, Self::Error> {
+        EmailRef::from_str(raw)
+    }
+}
+
+impl<'a> From<&'a Email> for EmailRef<'a> {
+    fn from(email: &'a Email) -> Self {
+        email.as_email_ref()
+    }
+}
+
+impl<'a, 'b> PartialEq<&'b str> for EmailRef<'a> {
+    fn eq(&self, other: &&'b str) -> bool {
+        self.raw_email == *other
+    }
+}
+
+impl PartialEq<Email> for EmailRef<'_> {
+    fn eq(&self, other: &Email) -> bool {
+        self.raw_email == other.as_str()
+    }
+}
+
+impl PartialEq<EmailRef<'_>> for Email {
+    fn eq(&self, other: &EmailRef<'_>) -> bool {
+        self.as_str() == other.raw_email
+    }
+}
+
+#[cfg(test)]
+mod test_from_str {
+    use super::*;
+    use crate::EmailErrorKind;
+
+    #[test]
+    fn it_should_borrow_valid_emails() {
+        let raw = String::from("john@example.com");
+        let email = EmailRef::from_str(&raw).unwrap();
+
+        assert!(::core::ptr::eq(email.as_str(), raw.as_str()));
+    }
+
+    #[test]
+    fn it_should_reject_invalid_emails() {
+        let err = EmailRef::from_str("john.example.com").unwrap_err();
+
+        assert_eq!(err.kind(), &EmailErrorKind::MissingSeparator);
+    }
+
+    #[test]
+    fn it_should_use_the_policy() {
+        let policy = ValidationPolicy::new().require_tld(true);
+
+        assert!(EmailRef::from_str_with("john@localhost", policy).is_err());
+    }
+}
+
+#[cfg(test)]
+mod test_accessors {
+    use super::*;
+
+    #[test]
+    fn it_should_return_the_same_as_email() {
+        let raw = "jöhn+news@exämple.com";
+        let email_ref = EmailRef::from_str(raw).unwrap();
+        let email = Email::from_str(raw).unwrap();
+
+        assert_eq!(email_ref.local_part(), email.local_part());
+        assert_eq!(email_ref.domain(), email.domain());
+        assert_eq!(email_ref.subaddress(), email.subaddress());
+        assert_eq!(email_ref.requires_smtputf8(), email.requires_smtputf8());
+        assert_eq!(email_ref.requires_idna(), email.requires_idna());
+    }
+
+    #[test]
+    fn it_should_build_the_same_emails_as_email() {
+        let raw = "john+news@Bücher.de";
+        let email_ref = EmailRef::from_str(raw).unwrap();
+        let email = Email::from_str(raw).unwrap();
+
+        assert_eq!(email_ref.without_subaddress(), email.without_subaddress());
+        assert_eq!(
+            email_ref.with_subaddress("shop").unwrap(),
+            email.with_subaddress("shop").unwrap()
+        );
+        assert_eq!(
+            email_ref.to_ascii_domain().unwrap(),
+            "john+news@xn--bcher-kva.de"
+        );
+        assert_eq!(
+            email_ref.to_unicode_domain().unwrap(),
+            email.to_unicode_domain().unwrap()
+        );
+    }
+
+    #[test]
+    fn it_should_outlive_itself_when_borrowing() {
+        let raw = "john+news@example.com";
+        let domain = EmailRef::from_str(raw).unwrap().domain();
+
+        assert_eq!(domain, "example.com");
+    }
+}
+
+#[cfg(test)]
+mod test_to_owned {
+    use super::*;
+
+    #[test]
+    fn it_should_convert_to_email() {
+        let email_ref = EmailRef::from_str(r#""john@home"@example.com"#).unwrap();
+        let email = email_ref.to_owned();
+
+        assert_eq!(email, email_ref);
+        assert_eq!(email.local_part(), r#""john@home""#);
+        assert_eq!(email.as_email_ref(), email_ref);
+    }
+}
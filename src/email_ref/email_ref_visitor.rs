@@ -0,0 +1,27 @@
+use ::alloc::format;
+use ::core::fmt::Formatter;
+use ::core::fmt::Result as FmtResult;
+use ::serde::de::Error as SerdeDeError;
+use ::serde::de::Visitor;
+
+use crate::EmailRef;
+
+pub struct EmailRefVisitor;
+
+impl<'de> Visitor<'de> for EmailRefVisitor {
+    type Value = EmailRef<'de>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+        formatter.write_str("a valid email address, which can be borrowed")
+    }
+
+    fn visit_borrowed_str<E>(self, raw_email: &'de str) -> Result<Self::Value, E>
+    where
+        E: SerdeDeError,
+    {
+        EmailRef::from_str(raw_email).map_err(|err| {
+            let msg = format!("{}", err);
+            SerdeDeError::custom(msg)
+        })
+    }
+}
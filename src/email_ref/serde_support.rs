@@ -0,0 +1,82 @@
+use ::serde::Deserialize;
+use ::serde::Deserializer;
+use ::serde::Serialize;
+use ::serde::Serializer;
+
+use crate::EmailRef;
+use crate::EmailRefVisitor;
+
+impl Serialize for EmailRef<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// Deserialising needs the text to be borrowed from the input.
+/// Strings which cannot be borrowed, such as those with escapes in JSON,
+/// will fail to deserialise. Use `Email` for those.
+impl<'de: 'a, 'a> Deserialize<'de> for EmailRef<'a> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(EmailRefVisitor)
+    }
+}
+
+#[cfg(test)]
+mod test_serialisation {
+    use super::*;
+    use ::serde_json;
+
+    #[test]
+    fn it_should_serialise_as_a_string() {
+        let email = EmailRef::from_str("john@example.com").unwrap();
+        let raw = serde_json::to_string(&email).unwrap();
+
+        assert_eq!(raw, r#""john@example.com""#);
+    }
+}
+
+#[cfg(test)]
+mod test_deserialisation {
+    use super::*;
+    use ::serde::Deserialize;
+    use ::serde_json;
+
+    #[derive(Deserialize)]
+    struct Person<'a> {
+        #[serde(borrow)]
+        email: EmailRef<'a>,
+    }
+
+    #[test]
+    fn it_should_deserialise_borrowing_the_input() {
+        let data = String::from(r#"{ "email": "john@example.com" }"#);
+
+        let person: Person = serde_json::from_str(&data).unwrap();
+
+        assert_eq!(person.email, "john@example.com");
+        assert!(data
+            .as_bytes()
+            .as_ptr_range()
+            .contains(&person.email.as_str().as_ptr()));
+    }
+
+    #[test]
+    fn it_should_explain_why_an_email_did_not_deserialise() {
+        let err = serde_json::from_str::<EmailRef>(r#""john@exam ple.com""#).unwrap_err();
+
+        assert!(err.to_string().contains("invalid character ' ' at byte 9"));
+    }
+
+    #[test]
+    fn it_should_not_deserialise_strings_which_cannot_be_borrowed() {
+        let result = serde_json::from_str::<EmailRef>(r#""john\u0040example.com""#);
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,230 @@
+use ::alloc::format;
+use ::alloc::string::String;
+use ::alloc::vec::Vec;
+
+/// Decodes an RFC 2047 encoded-word, returning None if it is not one.
+/// These are used for non-ASCII text in headers,
+/// i.e. `=?UTF-8?B?SsO2aG4=?=` is `Jöhn`.
+///
+/// The charsets supported are UTF-8, US-ASCII, and ISO-8859-1.
+/// Any other charset is treated as not being an encoded-word,
+/// leaving the text to be displayed as it is.
+pub(crate) fn decode_encoded_word(word: &str) -> Option<String> {
+    let inner = word.strip_prefix("=?")?.strip_suffix("?=")?;
+    let mut parts = inner.splitn(3, '?');
+    let charset = parts.next()?;
+    let encoding = parts.next()?;
+    let text = parts.next()?;
+
+    // RFC 2231 allows a language after the charset, i.e. `UTF-8*en`.
+    let charset = charset.split('*').next()?;
+
+    let bytes = match encoding {
+        "B" | "b" => decode_base64(text)?,
+        "Q" | "q" => decode_q(text)?,
+        _ => return None,
+    };
+
+    match charset.to_ascii_uppercase().as_str() {
+        "UTF-8" | "US-ASCII" => String::from_utf8(bytes).ok(),
+        "ISO-8859-1" | "LATIN1" => Some(bytes.into_iter().map(char::from).collect()),
+        _ => None,
+    }
+}
+
+/// Encodes text as RFC 2047 encoded-words, using UTF-8 and base64.
+///
+/// Long text is split over multiple encoded-words, at character boundaries.
+/// Each is short enough to fit on a header line with room to spare.
+pub(crate) fn encode_encoded_words(text: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut chunk_start = 0;
+
+    for (position, character) in text.char_indices() {
+        if position + character.len_utf8() - chunk_start > ENCODED_WORD_MAX_BYTES {
+            words.push(encode_encoded_word(&text[chunk_start..position]));
+            chunk_start = position;
+        }
+    }
+
+    if chunk_start < text.len() || words.is_empty() {
+        words.push(encode_encoded_word(&text[chunk_start..]));
+    }
+
+    words
+}
+
+/// The number of bytes of text in each encoded-word.
+/// This gives 52 characters of base64, and 64 characters in total.
+const ENCODED_WORD_MAX_BYTES: usize = 39;
+
+fn encode_encoded_word(text: &str) -> String {
+    format!("=?UTF-8?B?{}?=", encode_base64(text.as_bytes()))
+}
+
+fn encode_base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let buffer = chunk.iter().enumerate().fold(0u32, |buffer, (i, &byte)| {
+            buffer | u32::from(byte) << (16 - i * 8)
+        });
+
+        for i in 0..4 {
+            if i <= chunk.len() {
+                let index = (buffer >> (18 - i * 6)) & 0x3F;
+                encoded.push(char::from(ALPHABET[index as usize]));
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+
+    encoded
+}
+
+fn decode_q(text: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(text.len());
+    let mut iter = text.bytes();
+
+    while let Some(byte) = iter.next() {
+        match byte {
+            b'_' => bytes.push(b' '),
+            b'=' => {
+                let high = hex_value(iter.next()?)?;
+                let low = hex_value(iter.next()?)?;
+                bytes.push(high << 4 | low);
+            }
+            b'?' | b' ' => return None,
+            _ => bytes.push(byte),
+        }
+    }
+
+    Some(bytes)
+}
+
+/// Returns the value of an ASCII hex digit, in either case.
+pub(crate) fn hex_value(byte: u8) -> Option<u8> {
+    match byte {
+        b'0'..=b'9' => Some(byte - b'0'),
+        b'A'..=b'F' => Some(byte - b'A' + 10),
+        b'a'..=b'f' => Some(byte - b'a' + 10),
+        _ => None,
+    }
+}
+
+fn decode_base64(text: &str) -> Option<Vec<u8>> {
+    let text = text.trim_end_matches('=');
+    let mut bytes = Vec::with_capacity(text.len() * 3 / 4);
+    let mut buffer = 0u32;
+    let mut num_bits = 0;
+
+    for byte in text.bytes() {
+        let value = match byte {
+            b'A'..=b'Z' => byte - b'A',
+            b'a'..=b'z' => byte - b'a' + 26,
+            b'0'..=b'9' => byte - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            _ => return None,
+        };
+
+        buffer = buffer << 6 | u32::from(value);
+        num_bits += 6;
+
+        if num_bits >= 8 {
+            num_bits -= 8;
+            bytes.push((buffer >> num_bits) as u8);
+            buffer &= (1 << num_bits) - 1;
+        }
+    }
+
+    Some(bytes)
+}
+
+#[cfg(test)]
+mod test_decode_encoded_word {
+    use super::*;
+
+    #[test]
+    fn it_should_decode_base64_utf8() {
+        assert_eq!(
+            decode_encoded_word("=?UTF-8?B?SsO2aG4=?="),
+            Some("Jöhn".to_string())
+        );
+    }
+
+    #[test]
+    fn it_should_decode_q_utf8() {
+        assert_eq!(
+            decode_encoded_word("=?utf-8?q?J=C3=B6hn_Doe?="),
+            Some("Jöhn Doe".to_string())
+        );
+    }
+
+    #[test]
+    fn it_should_decode_latin1() {
+        assert_eq!(
+            decode_encoded_word("=?ISO-8859-1?Q?J=F6hn?="),
+            Some("Jöhn".to_string())
+        );
+    }
+
+    #[test]
+    fn it_should_ignore_the_language() {
+        assert_eq!(
+            decode_encoded_word("=?UTF-8*en?Q?John?="),
+            Some("John".to_string())
+        );
+    }
+
+    #[test]
+    fn it_should_not_decode_plain_words() {
+        assert_eq!(decode_encoded_word("John"), None);
+    }
+
+    #[test]
+    fn it_should_not_decode_unknown_charsets() {
+        assert_eq!(decode_encoded_word("=?KOI8-R?Q?John?="), None);
+    }
+
+    #[test]
+    fn it_should_not_decode_malformed_words() {
+        assert_eq!(decode_encoded_word("=?UTF-8?B?S$?="), None);
+        assert_eq!(decode_encoded_word("=?UTF-8?Q?J=C?="), None);
+        assert_eq!(decode_encoded_word("=?UTF-8?X?John?="), None);
+    }
+}
+
+#[cfg(test)]
+mod test_encode_encoded_words {
+    use super::*;
+
+    #[test]
+    fn it_should_encode_as_base64_utf8() {
+        assert_eq!(encode_encoded_words("Jöhn"), vec!["=?UTF-8?B?SsO2aG4=?="]);
+    }
+
+    #[test]
+    fn it_should_pad_base64() {
+        assert_eq!(encode_encoded_words("a"), vec!["=?UTF-8?B?YQ==?="]);
+        assert_eq!(encode_encoded_words("ab"), vec!["=?UTF-8?B?YWI=?="]);
+        assert_eq!(encode_encoded_words("abc"), vec!["=?UTF-8?B?YWJj?="]);
+    }
+
+    #[test]
+    fn it_should_split_long_text_at_character_boundaries() {
+        let text = "ö".repeat(30);
+        let words = encode_encoded_words(&text);
+
+        assert_eq!(words.len(), 2);
+        assert!(words.iter().all(|word| word.len() <= 75));
+
+        let decoded: String = words
+            .iter()
+            .map(|word| decode_encoded_word(word).unwrap())
+            .collect();
+        assert_eq!(decoded, text);
+    }
+}
@@ -0,0 +1,266 @@
+//! Finding email addresses within free text, such as support tickets and chat logs.
+//!
+//! ```rust
+//! use ::serde_email::extract::find_emails;
+//!
+//! let text = "Contact <john@example.com> or mailto:jane@example.com.";
+//! let emails: Vec<_> = find_emails(text).map(|(_, email)| email.to_string()).collect();
+//!
+//! assert_eq!(emails, vec!["john@example.com", "jane@example.com"]);
+//! ```
+
+use ::core::ops::Range;
+
+use crate::Email;
+use crate::ValidationPolicy;
+
+/// Finds every email address within the text given.
+///
+/// Each match is returned with the byte range it was found at.
+/// Surrounding punctuation, such as brackets, quotes, a `mailto:` prefix,
+/// or a full stop at the end of a sentence, is not included.
+///
+/// Every match is validated in the same way as `is_valid_email`.
+/// Quoted local parts are not searched for.
+pub fn find_emails(text: &str) -> FindEmails<'_> {
+    find_emails_with(text, ValidationPolicy::new())
+}
+
+/// Finds every email address within the text given,
+/// validating each one using the rules of the policy.
+///
+/// i.e. this can be used to require a top level domain,
+/// to avoid matching text such as `me@home`.
+pub fn find_emails_with<P>(text: &str, policy: P) -> FindEmails<'_>
+where
+    P: Into<ValidationPolicy>,
+{
+    FindEmails {
+        text,
+        policy: policy.into(),
+        position: 0,
+    }
+}
+
+/// An iterator over the email addresses within some text,
+/// returned by `find_emails` and `find_emails_with`.
+#[derive(Clone, Debug)]
+pub struct FindEmails<'a> {
+    text: &'a str,
+    policy: ValidationPolicy,
+    position: usize,
+}
+
+impl Iterator for FindEmails<'_> {
+    type Item = (Range<usize>, Email);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut search_start = self.position;
+
+        while let Some(offset) = self.text[search_start..].find('@') {
+            let at_index = search_start + offset;
+            search_start = at_index + 1;
+
+            let Some(range) = find_candidate(self.text, self.position, at_index) else {
+                continue;
+            };
+
+            if let Ok(email) = Email::from_str_with(&self.text[range.clone()], self.policy) {
+                self.position = range.end;
+                return Some((range, email));
+            }
+        }
+
+        self.position = self.text.len();
+        None
+    }
+}
+
+/// Returns the range of the text around the `@` which may be an email,
+/// going no further back than `min_start`.
+fn find_candidate(text: &str, min_start: usize, at_index: usize) -> Option<Range<usize>> {
+    let before = &text[min_start..at_index];
+    let local_length: usize = before
+        .chars()
+        .rev()
+        .take_while(|&c| is_local_part_char(c))
+        .map(char::len_utf8)
+        .sum();
+    let local_part = before[before.len() - local_length..].trim_start_matches(['.', '\'']);
+    let start = at_index - local_part.len();
+
+    let after = &text[at_index + 1..];
+    let domain_length = if after.starts_with('[') {
+        after.find(']')? + 1
+    } else {
+        let length: usize = after
+            .chars()
+            .take_while(|&c| is_domain_char(c))
+            .map(char::len_utf8)
+            .sum();
+
+        after[..length].trim_end_matches(['.', '-']).len()
+    };
+    let end = at_index + 1 + domain_length;
+
+    if local_part.is_empty() || domain_length == 0 {
+        return None;
+    }
+
+    Some(start..end)
+}
+
+/// The characters searched for in a local part.
+///
+/// This is narrower than what is valid, as characters such as `{`, `|`, and `` ` ``
+/// are more likely to be punctuation around an address than a part of it.
+fn is_local_part_char(c: char) -> bool {
+    c.is_alphanumeric() || "!#$%&'*+-=?^_~.".contains(c)
+}
+
+fn is_domain_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '-' || c == '.'
+}
+
+#[cfg(test)]
+mod test_find_emails {
+    use super::*;
+
+    fn find(text: &str) -> Vec<(Range<usize>, String)> {
+        find_emails(text)
+            .map(|(range, email)| (range, email.to_string()))
+            .collect()
+    }
+
+    fn find_strs(text: &str) -> Vec<String> {
+        find(text).into_iter().map(|(_, email)| email).collect()
+    }
+
+    #[test]
+    fn it_should_find_an_email_in_prose() {
+        assert_eq!(
+            find("Please email john@example.com about it"),
+            vec![(13..29, "john@example.com".to_string())]
+        );
+    }
+
+    #[test]
+    fn it_should_find_nothing_in_text_without_emails() {
+        assert!(find("Nothing to see here, @mentions aside.").is_empty());
+    }
+
+    #[test]
+    fn it_should_find_multiple_emails() {
+        assert_eq!(
+            find_strs("a@x.com,b@y.com; c@z.com"),
+            vec!["a@x.com", "b@y.com", "c@z.com"]
+        );
+    }
+
+    #[test]
+    fn it_should_skip_trailing_punctuation() {
+        assert_eq!(
+            find_strs("Send it to john@example.com."),
+            vec!["john@example.com"]
+        );
+        assert_eq!(
+            find_strs("Was it john@example.com?"),
+            vec!["john@example.com"]
+        );
+        assert_eq!(find_strs("john@example.com!"), vec!["john@example.com"]);
+        assert_eq!(find_strs("john@example.com-"), vec!["john@example.com"]);
+    }
+
+    #[test]
+    fn it_should_skip_angle_brackets() {
+        assert_eq!(
+            find("John <john@example.com>"),
+            vec![(6..22, "john@example.com".to_string())]
+        );
+    }
+
+    #[test]
+    fn it_should_skip_parentheses() {
+        assert_eq!(find_strs("(john@example.com)"), vec!["john@example.com"]);
+    }
+
+    #[test]
+    fn it_should_skip_quotes() {
+        assert_eq!(find_strs("'john@example.com'"), vec!["john@example.com"]);
+        assert_eq!(find_strs("\"john@example.com\""), vec!["john@example.com"]);
+    }
+
+    #[test]
+    fn it_should_skip_mailto_prefixes() {
+        assert_eq!(
+            find("mailto:john@example.com"),
+            vec![(7..23, "john@example.com".to_string())]
+        );
+    }
+
+    #[test]
+    fn it_should_keep_apostrophes_within_local_parts() {
+        assert_eq!(
+            find_strs("o'brien@example.com"),
+            vec!["o'brien@example.com"]
+        );
+    }
+
+    #[test]
+    fn it_should_find_subaddresses() {
+        assert_eq!(
+            find_strs("john.doe+news@example.com"),
+            vec!["john.doe+news@example.com"]
+        );
+    }
+
+    #[test]
+    fn it_should_find_domain_literals() {
+        assert_eq!(
+            find_strs("at john@[192.168.0.1]."),
+            vec!["john@[192.168.0.1]"]
+        );
+    }
+
+    #[test]
+    fn it_should_find_non_ascii_emails() {
+        let text = "Écrivez à jöhn@exämple.com.";
+
+        let found = find(text);
+        assert_eq!(found[0].1, "jöhn@exämple.com");
+        assert_eq!(&text[found[0].0.clone()], "jöhn@exämple.com");
+    }
+
+    #[test]
+    fn it_should_skip_invalid_emails() {
+        assert_eq!(
+            find_strs("bad@-example.com then good@example.com"),
+            vec!["good@example.com"]
+        );
+        assert!(find_strs("john@example..com").is_empty());
+    }
+
+    #[test]
+    fn it_should_return_ranges_into_the_text() {
+        let text = "Hi (jane@example.com), and <john@example.com>.";
+
+        for (range, email) in find_emails(text) {
+            assert_eq!(&text[range], email.as_str());
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_find_emails_with {
+    use super::*;
+
+    #[test]
+    fn it_should_use_the_policy() {
+        let policy = ValidationPolicy::new().require_tld(true);
+        let emails: Vec<_> = find_emails_with("me@home or me@example.com", policy)
+            .map(|(_, email)| email.to_string())
+            .collect();
+
+        assert_eq!(emails, vec!["me@example.com"]);
+    }
+}
@@ -0,0 +1,229 @@
+use ::alloc::format;
+use ::alloc::string::String;
+use ::alloc::string::ToString;
+use ::alloc::vec::Vec;
+use ::core::borrow::Borrow;
+
+use crate::encoded_word::encode_encoded_words;
+use crate::mailbox::format_display_name;
+use crate::mailbox::has_control_characters;
+use crate::Mailbox;
+
+/// The maximum length of a header line, not including the line ending.
+const LINE_MAX_LENGTH: usize = 78;
+
+/// Formats the mailboxes given as an address header, such as `To` or `Cc`.
+///
+/// Long headers are folded over multiple lines using `\r\n `,
+/// so that lines fit within 78 characters where possible.
+/// A single word longer than that, such as a long email, is left on a line of its own.
+/// Display names are quoted when needed,
+/// and display names which are non-ASCII, or hold control characters such as line breaks,
+/// are written as RFC 2047 encoded-words.
+///
+/// The result does not include a line ending after the last line.
+///
+/// ```rust
+/// use ::serde_email::format_address_header;
+/// use ::serde_email::Mailbox;
+///
+/// let to = vec![
+///     Mailbox::from_str("Jöhn <john@example.com>").unwrap(),
+///     Mailbox::from_str(r#""Doe, Jane" <jane@example.com>"#).unwrap(),
+/// ];
+///
+/// assert_eq!(
+///     format_address_header("To", &to),
+///     "To: =?UTF-8?B?SsO2aG4=?= <john@example.com>, \"Doe, Jane\" <jane@example.com>",
+/// );
+/// ```
+///
+/// Only the display names are encoded.
+/// Non-ASCII email addresses are written as they are,
+/// as per RFC 6532, and need the SMTPUTF8 extension to be sent.
+pub fn format_address_header<I, M>(header_name: &str, mailboxes: I) -> String
+where
+    I: IntoIterator<Item = M>,
+    M: Borrow<Mailbox>,
+{
+    let mut header = format!("{header_name}:");
+    let mut line_length = header.len();
+    let mut mailboxes = mailboxes.into_iter().peekable();
+
+    while let Some(mailbox) = mailboxes.next() {
+        let mut tokens = mailbox_tokens(mailbox.borrow());
+        if mailboxes.peek().is_some() {
+            if let Some(last_token) = tokens.last_mut() {
+                last_token.push(',');
+            }
+        }
+
+        for token in tokens {
+            let is_line_start = line_length == header_name.len() + 1 && header.ends_with(':');
+            if !is_line_start && line_length + 1 + token.len() > LINE_MAX_LENGTH {
+                header.push_str("\r\n");
+                line_length = 0;
+            }
+
+            header.push(' ');
+            header.push_str(&token);
+            line_length += 1 + token.len();
+        }
+    }
+
+    header
+}
+
+/// Splits the mailbox into the pieces which lines can be folded between.
+fn mailbox_tokens(mailbox: &Mailbox) -> Vec<String> {
+    let mut tokens = match mailbox.display_name() {
+        Some(display_name) if !display_name.is_ascii() || has_control_characters(display_name) => {
+            encode_encoded_words(display_name)
+        }
+        Some(display_name) => format_display_name(display_name)
+            .split(' ')
+            .map(str::to_string)
+            .collect(),
+        None => Vec::new(),
+    };
+
+    if tokens.is_empty() {
+        tokens.push(mailbox.email().to_string());
+    } else {
+        tokens.push(format!("<{}>", mailbox.email()));
+    }
+
+    tokens
+}
+
+#[cfg(test)]
+mod test_format_address_header {
+    use super::*;
+    use crate::AddressList;
+    use crate::Email;
+
+    fn mailbox(raw: &str) -> Mailbox {
+        Mailbox::from_str(raw).unwrap()
+    }
+
+    fn unfold(header: &str) -> String {
+        header.replace("\r\n", "")
+    }
+
+    #[test]
+    fn it_should_format_a_single_email() {
+        let to = [Mailbox::from(Email::from_str("john@example.com").unwrap())];
+
+        assert_eq!(format_address_header("To", &to), "To: john@example.com");
+    }
+
+    #[test]
+    fn it_should_format_display_names() {
+        let to = [mailbox("John Doe <john@example.com>")];
+
+        assert_eq!(
+            format_address_header("To", &to),
+            "To: John Doe <john@example.com>"
+        );
+    }
+
+    #[test]
+    fn it_should_separate_mailboxes_with_commas() {
+        let to = [mailbox("a@x.com"), mailbox("B <b@y.com>")];
+
+        assert_eq!(format_address_header("Cc", to), "Cc: a@x.com, B <b@y.com>");
+    }
+
+    #[test]
+    fn it_should_quote_display_names_with_specials() {
+        let to = [mailbox(r#""Doe, Jane" <jane@example.com>"#)];
+
+        assert_eq!(
+            format_address_header("To", &to),
+            r#"To: "Doe, Jane" <jane@example.com>"#
+        );
+    }
+
+    #[test]
+    fn it_should_keep_quoted_local_parts_with_angle_brackets() {
+        let to = [Mailbox::new(
+            "Name",
+            r#""a <b"@example.com"#.parse().unwrap(),
+        )];
+
+        assert_eq!(
+            format_address_header("To", &to),
+            r#"To: Name <"a <b"@example.com>"#
+        );
+    }
+
+    #[test]
+    fn it_should_encode_non_ascii_display_names() {
+        let to = [mailbox("Jöhn Doe <john@example.com>")];
+
+        assert_eq!(
+            format_address_header("To", &to),
+            "To: =?UTF-8?B?SsO2aG4gRG9l?= <john@example.com>"
+        );
+    }
+
+    #[test]
+    fn it_should_encode_display_names_with_line_breaks() {
+        let to = [Mailbox::new(
+            "Evil\r\nBcc: victim@x.com",
+            "a@x.com".parse().unwrap(),
+        )];
+        let header = format_address_header("To", &to);
+
+        assert_eq!(
+            header,
+            "To: =?UTF-8?B?RXZpbA0KQmNjOiB2aWN0aW1AeC5jb20=?= <a@x.com>"
+        );
+        assert!(!header.contains('\r') && !header.contains('\n'));
+    }
+
+    #[test]
+    fn it_should_fold_long_headers() {
+        let to: Vec<Mailbox> = (0..10)
+            .map(|i| mailbox(&format!("Person Number {i} <person.{i}@example.com>")))
+            .collect();
+        let header = format_address_header("To", &to);
+
+        assert!(header.contains("\r\n "));
+        for line in header.split("\r\n") {
+            assert!(line.len() <= 78, "line is too long: {line:?}");
+        }
+    }
+
+    #[test]
+    fn it_should_fold_long_encoded_display_names() {
+        let name = "Jöhn ".repeat(20);
+        let to = [Mailbox::new(
+            name.trim(),
+            "john@example.com".parse().unwrap(),
+        )];
+        let header = format_address_header("To", &to);
+
+        for line in header.split("\r\n") {
+            assert!(line.len() <= 78, "line is too long: {line:?}");
+        }
+    }
+
+    #[test]
+    fn it_should_parse_back_to_the_same_mailboxes() {
+        let to = vec![
+            mailbox("Jöhn Dœ <john@example.com>"),
+            mailbox(r#""Doe, Jane \"JD\"" <jane@example.com>"#),
+            mailbox("plain@example.com"),
+            Mailbox::new("ö".repeat(50), "long@example.com".parse().unwrap()),
+        ];
+        let header = format_address_header("To", &to);
+        let value = unfold(&header).strip_prefix("To:").unwrap().to_string();
+
+        let parsed: Vec<Mailbox> = AddressList::parse(value)
+            .valid_mailboxes()
+            .cloned()
+            .collect();
+        assert_eq!(parsed, to);
+    }
+}
@@ -0,0 +1,32 @@
+/// The grammar an email address is parsed with, set using `ValidationPolicy::grammar`.
+///
+/// In every grammar domain names must be host names,
+/// made of letters, digits, and hyphens, as other domains cannot be delivered to.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Grammar {
+    /// The `addr-spec` of RFC 5322, used in message headers. This is the default.
+    ///
+    /// The local part can be dotted atoms or a quoted string,
+    /// and the domain can be a literal with any text, such as `[anything]`.
+    #[default]
+    Rfc5322,
+
+    /// The `Mailbox` of RFC 5321, used by SMTP.
+    ///
+    /// This is stricter than RFC 5322. Quoted strings cannot contain tabs,
+    /// and domain literals must be an IPv4 address, such as `[192.168.0.1]`,
+    /// an IPv6 address, such as `[IPv6:2001:db8::1]`,
+    /// or a tagged literal, such as `[tag:content]`.
+    Rfc5321,
+
+    /// The grammar of `<input type="email">` in the WHATWG HTML spec.
+    ///
+    /// Local parts cannot be quoted, but dots can appear anywhere in them,
+    /// i.e. `.john..doe.@example.com` is valid.
+    /// Domain literals are not allowed, and only ASCII is allowed.
+    ///
+    /// This is only the grammar. The length limits of the policy still apply,
+    /// so it is stricter than browsers, which have no limits.
+    /// Use `ValidationMode::Html5` to match browsers exactly.
+    Html5,
+}
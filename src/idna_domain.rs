@@ -0,0 +1,147 @@
+use ::alloc::string::String;
+use ::alloc::string::ToString;
+use ::idna::domain_to_ascii;
+use ::idna::domain_to_unicode;
+
+use crate::EmailErrorKind;
+
+/// Converts a domain to ASCII, using the UTS #46 IDNA mapping.
+/// i.e. `bücher.de` becomes `xn--bcher-kva.de`.
+///
+/// Domain literals are returned as they are.
+///
+/// `domain_start` is the byte offset of the domain within the whole address,
+/// and is used for the position of any errors.
+pub(crate) fn domain_to_ascii_at(
+    domain: &str,
+    domain_start: usize,
+) -> Result<String, EmailErrorKind> {
+    if domain.starts_with('[') {
+        return Ok(domain.to_string());
+    }
+
+    domain_to_ascii(domain).map_err(|_| EmailErrorKind::InvalidIdnaLabel {
+        position: domain_start + find_invalid_label(domain),
+    })
+}
+
+/// Converts a domain to Unicode, using the UTS #46 IDNA mapping.
+/// i.e. `xn--bcher-kva.de` becomes `bücher.de`.
+///
+/// Domain literals are returned as they are.
+///
+/// `domain_start` is the byte offset of the domain within the whole address,
+/// and is used for the position of any errors.
+pub(crate) fn domain_to_unicode_at(
+    domain: &str,
+    domain_start: usize,
+) -> Result<String, EmailErrorKind> {
+    if domain.starts_with('[') {
+        return Ok(domain.to_string());
+    }
+
+    let (unicode_domain, result) = domain_to_unicode(domain);
+    result.map_err(|_| EmailErrorKind::InvalidIdnaLabel {
+        position: domain_start + find_invalid_label(domain),
+    })?;
+
+    Ok(unicode_domain)
+}
+
+/// Returns the offset of the first label which fails IDNA on its own.
+/// If every label passes alone, then the whole domain is at fault.
+fn find_invalid_label(domain: &str) -> usize {
+    let mut label_start = 0;
+
+    for label in domain.split('.') {
+        if domain_to_ascii(label).is_err() {
+            return label_start;
+        }
+
+        label_start += label.len() + 1;
+    }
+
+    0
+}
+
+#[cfg(test)]
+mod test_domain_to_ascii_at {
+    use super::*;
+
+    #[test]
+    fn it_should_convert_unicode_domains_to_punycode() {
+        assert_eq!(
+            domain_to_ascii_at("bücher.de", 0),
+            Ok("xn--bcher-kva.de".to_string())
+        );
+    }
+
+    #[test]
+    fn it_should_map_uppercase_to_lowercase() {
+        assert_eq!(
+            domain_to_ascii_at("BÜCHER.de", 0),
+            Ok("xn--bcher-kva.de".to_string())
+        );
+    }
+
+    #[test]
+    fn it_should_leave_ascii_domains_alone() {
+        assert_eq!(
+            domain_to_ascii_at("example.com", 0),
+            Ok("example.com".to_string())
+        );
+    }
+
+    #[test]
+    fn it_should_leave_domain_literals_alone() {
+        assert_eq!(
+            domain_to_ascii_at("[192.168.0.1]", 0),
+            Ok("[192.168.0.1]".to_string())
+        );
+    }
+
+    #[test]
+    fn it_should_report_the_position_of_the_invalid_label() {
+        assert_eq!(
+            domain_to_ascii_at("example.xn--a.com", 5),
+            Err(EmailErrorKind::InvalidIdnaLabel { position: 13 })
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_domain_to_unicode_at {
+    use super::*;
+
+    #[test]
+    fn it_should_convert_punycode_domains_to_unicode() {
+        assert_eq!(
+            domain_to_unicode_at("xn--bcher-kva.de", 0),
+            Ok("bücher.de".to_string())
+        );
+    }
+
+    #[test]
+    fn it_should_leave_ascii_domains_alone() {
+        assert_eq!(
+            domain_to_unicode_at("example.com", 0),
+            Ok("example.com".to_string())
+        );
+    }
+
+    #[test]
+    fn it_should_leave_domain_literals_alone() {
+        assert_eq!(
+            domain_to_unicode_at("[192.168.0.1]", 0),
+            Ok("[192.168.0.1]".to_string())
+        );
+    }
+
+    #[test]
+    fn it_should_report_the_position_of_the_invalid_label() {
+        assert_eq!(
+            domain_to_unicode_at("example.xn--a.com", 5),
+            Err(EmailErrorKind::InvalidIdnaLabel { position: 13 })
+        );
+    }
+}
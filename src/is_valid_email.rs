@@ -1,12 +1,111 @@
-use ::email_address::EmailAddress;
-use ::std::convert::AsRef;
+use ::core::convert::AsRef;
+
+use crate::ValidationPolicy;
 
 /// Tests if the given string is a valid email or not.
 ///
-/// The underlying implementation is by the [email_address crate](https://crates.io/crates/email_address).
+/// Only the address itself is accepted. Display names,
+/// such as `John <john@example.com>`, are rejected.
+///
+/// Use `validate_email` to find out why an email is invalid,
+/// and `is_valid_email_with` to validate using different rules.
 pub fn is_valid_email<S>(raw: S) -> bool
 where
     S: AsRef<str>,
 {
-    EmailAddress::is_valid(raw.as_ref())
+    ValidationPolicy::new().is_valid(raw)
+}
+
+/// Tests if the given string is a valid email or not,
+/// using the rules of the `ValidationPolicy` or `ValidationMode` given.
+///
+/// ```rust
+/// use ::serde_email::is_valid_email_with;
+/// use ::serde_email::ValidationMode;
+///
+/// // Matches `<input type="email">` in browsers.
+/// assert!(is_valid_email_with("john..doe@example.com", ValidationMode::Html5));
+/// ```
+pub fn is_valid_email_with<S, P>(raw: S, policy: P) -> bool
+where
+    S: AsRef<str>,
+    P: Into<ValidationPolicy>,
+{
+    policy.into().is_valid(raw)
+}
+
+/// Checks against the `email_address` crate, which was used for validation up to 3.2.
+#[cfg(test)]
+mod test_email_address_compatibility {
+    use super::*;
+    use ::email_address::EmailAddress;
+
+    #[test]
+    fn it_should_agree_with_email_address() {
+        let corpus = [
+            "john@example.com",
+            "john.doe@example.com",
+            "john+tag@example.com",
+            "jo_hn@example.com",
+            "!#$%&'*+-/=?^_`{|}~@example.com",
+            "john@localhost",
+            "john@123.123.123.123",
+            "john@example.c",
+            "john@1example.com",
+            "john@xn--exmple-cua.com",
+            "jöhn@exämple.com",
+            "用户@例子.广告",
+            "john@[192.168.0.1]",
+            "john@[IPv6:2001:db8::1]",
+            "john@[IPv6:::1]",
+            "john@[tag:content]",
+            "\"john doe\"@example.com",
+            "\"john\\\"doe\"@example.com",
+            "\"a\tb\"@example.com",
+            "john..doe@example.com",
+            ".john@example.com",
+            "john.@example.com",
+            "john@example..com",
+            "john@-example.com",
+            "john@example-.com",
+            "john@example.com.",
+            "john@.example.com",
+            "@example.com",
+            "john@",
+            "john",
+            "john@@example.com",
+            "john doe@example.com",
+            "john@exa mple.com",
+            "john@[ 1.2.3.4 ]",
+            "\"\"@example.com",
+            "john(comment)@example.com",
+            "john@example.com (comment)",
+        ];
+
+        for raw in corpus {
+            assert_eq!(is_valid_email(raw), EmailAddress::is_valid(raw), "{raw:?}");
+        }
+    }
+
+    /// These are the addresses which `email_address` accepts, and which are now rejected.
+    /// Each is listed in the changelog.
+    #[test]
+    fn it_should_reject_addresses_email_address_accepted() {
+        let too_long = format!("joh@{}.com", vec!["a".repeat(61); 4].join("."));
+        assert_eq!(too_long.len(), 255);
+
+        let divergences = [
+            "John <john@example.com>",
+            "john@sub_domain.example.com",
+            "john@[]",
+            "john@[300.0.0.1]",
+            "john@[IPv6:nope]",
+            too_long.as_str(),
+        ];
+
+        for raw in divergences {
+            assert!(EmailAddress::is_valid(raw), "{raw:?}");
+            assert!(!is_valid_email(raw), "{raw:?}");
+        }
+    }
 }
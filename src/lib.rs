@@ -10,8 +10,17 @@
 //!
 //! ## Features
 //!
-//!  * `serde` **Default** - Enables serde serialisation and deserialisation.
-//!  * `sea-orm` - Enables Sea Orm use with DB entities.
+//!  * `std` **Default** - Implements `std::error::Error` for `EmailError`.
+//!    Without it the crate is `no_std`, and only needs `alloc`.
+//!  * `serde` **Default** - Enables serde serialisation and deserialisation. This works with or without `std`.
+//!  * `sea-orm` - Enables Sea Orm use with DB entities. This needs `std`.
+//!  * `public-suffix` - Embeds a snapshot of the [Public Suffix List](https://publicsuffix.org),
+//!    for finding the registrable domain of an address, and rejecting unknown top level domains.
+//!    The snapshot is from 2025-09-23. To use a newer list, set `SERDE_EMAIL_PUBLIC_SUFFIX_LIST`
+//!    to the absolute path of a `public_suffix_list.dat` file when building.
+//!    The list is from Mozilla, and is licensed under the [MPL 2.0](https://mozilla.org/MPL/2.0/).
+//!  * `disposable` - Embeds a list of disposable email domains, such as `mailinator.com`,
+//!    for detecting and rejecting throwaway addresses.
 //!
 //! ## Usage
 //!
@@ -23,6 +32,21 @@
 //! let email = Email::from_str("test@example.com").expect("A valid email address");
 //! ```
 //!
+//! ### Compile time checked email addresses
+//!
+//! With the `serde-email-macros` crate, email literals can be checked when compiling.
+//! An invalid email is a compile error.
+//!
+//! ```rust
+//! use ::serde_email::Email;
+//! use ::serde_email_macros::email;
+//!
+//! let email: Email = email!("ops@example.com");
+//!
+//! // It can also be used for statics.
+//! static NOREPLY: Email = email!("noreply@example.com");
+//! ```
+//!
 //! ### Validating the email address yourself
 //!
 //! ```rust
@@ -33,6 +57,134 @@
 //! }
 //! ```
 //!
+//! ### Finding out why an email address is invalid
+//!
+//! ```rust
+//! use ::serde_email::validate_email;
+//!
+//! if let Err(err) = validate_email(&"test@exa mple.com") {
+//!   // prints "invalid character ' ' at byte 8"
+//!   println!("{}", err.kind());
+//! }
+//! ```
+//!
+//! ### Validating with different rules
+//!
+//! ```rust
+//! use ::serde_email::Email;
+//! use ::serde_email::ValidationPolicy;
+//!
+//! let policy = ValidationPolicy::new()
+//!     .allow_domain_literals(false)
+//!     .require_tld(true);
+//!
+//! let email = Email::from_str_with("test@example.com", &policy).expect("A valid email address");
+//! ```
+//!
+//! The grammar can also be chosen, between RFC 5322 (the default), the stricter RFC 5321 used by SMTP,
+//! and the grammar of `<input type="email">` from the HTML spec.
+//!
+//! ```rust
+//! use ::serde_email::Grammar;
+//! use ::serde_email::ValidationPolicy;
+//!
+//! let policy = ValidationPolicy::new().grammar(Grammar::Rfc5321);
+//!
+//! assert!(!policy.is_valid("test@[300.0.0.1]"));
+//! ```
+//!
+//! Ready made policies are also available with `ValidationMode`.
+//! These can be used wherever a policy is taken.
+//!
+//! ```rust
+//! use ::serde_email::is_valid_email_with;
+//! use ::serde_email::ValidationMode;
+//!
+//! // Matches `<input type="email">` in browsers.
+//! assert!(is_valid_email_with("john..doe@example.com", ValidationMode::Html5));
+//! ```
+//!
+//! ### Normalising email addresses
+//!
+//! ```rust
+//! use ::serde_email::Normalizer;
+//!
+//! let email = Normalizer::new().normalize(" John@Example.COM ").expect("A valid email address");
+//!
+//! assert_eq!(email.as_str(), "John@example.com");
+//! ```
+//!
+//! ### Email addresses with display names
+//!
+//! ```rust
+//! use ::serde_email::Mailbox;
+//!
+//! let mailbox = Mailbox::from_str(r#""Doe, Jane" <jane@example.com>"#).expect("A valid mailbox");
+//!
+//! assert_eq!(mailbox.display_name(), Some("Doe, Jane"));
+//! assert_eq!(mailbox.email().as_str(), "jane@example.com");
+//! ```
+//!
+//! ### Finding email addresses in text
+//!
+//! ```rust
+//! use ::serde_email::extract::find_emails;
+//!
+//! let emails: Vec<_> = find_emails("Ask <john@example.com> or jane@example.com.")
+//!     .map(|(_range, email)| email)
+//!     .collect();
+//!
+//! assert_eq!(emails, vec!["john@example.com", "jane@example.com"]);
+//! ```
+//!
+//! ### Public suffixes
+//!
+//! **Required**, the `public-suffix` feature must be enabled.
+//!
+//! ```rust
+//! # #[cfg(feature = "public-suffix")]
+//! # {
+//! use ::serde_email::Email;
+//! use ::serde_email::ValidationPolicy;
+//!
+//! let email = Email::from_str("john@mail.example.co.uk").expect("A valid email address");
+//!
+//! assert_eq!(email.public_suffix(), Some("co.uk"));
+//! assert_eq!(email.registrable_domain(), Some("example.co.uk"));
+//!
+//! let policy = ValidationPolicy::new().require_known_tld(true);
+//!
+//! assert!(!policy.is_valid("john@example.unknowntld"));
+//! # }
+//! ```
+//!
+//! ### Disposable email addresses
+//!
+//! **Required**, the `disposable` feature must be enabled.
+//!
+//! `ValidationPolicy::reject_disposable_domains` only uses the built in list.
+//! Domains added to a `DisposableDomains` are checked with `DisposableDomains::check`.
+//!
+//! ```rust
+//! # #[cfg(feature = "disposable")]
+//! # {
+//! use ::serde_email::DisposableDomains;
+//! use ::serde_email::Email;
+//! use ::serde_email::ValidationPolicy;
+//!
+//! let email = Email::from_str("john@mailinator.com").expect("A valid email address");
+//! assert!(email.is_disposable());
+//!
+//! let policy = ValidationPolicy::new().reject_disposable_domains(true);
+//! assert!(!policy.is_valid("john@mailinator.com"));
+//!
+//! // Add your own domains to the built in list.
+//! let domains = DisposableDomains::new().with_list("throwaway.example.com");
+//! let email = Email::from_str("john@throwaway.example.com").expect("A valid email address");
+//! assert!(domains.check(&email).is_err());
+//! # }
+//! ```
+//!
 //! ### Serialisation / Deserialisation
 //!
 //! ```rust
@@ -69,6 +221,8 @@
 //! **Required**, the `sea-orm` feature must be enabled for Sea Orm support.
 //!
 //! ```rust
+//! # #[cfg(feature = "sea-orm")]
+//! # mod user {
 //! use ::sea_orm::entity::prelude::*;
 //! use ::serde::Deserialize;
 //! use ::serde::Serialize;
@@ -86,14 +240,98 @@
 //! pub enum Relation {}
 //!
 //! impl ActiveModelBehavior for ActiveModel {}
+//! # }
 //! ```
 //!
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+mod address_list;
+pub use self::address_list::*;
+
+mod address_list_entry;
+pub use self::address_list_entry::*;
+
+mod arc_email;
+pub use self::arc_email::*;
+
+mod caseless_domain_email;
+pub use self::caseless_domain_email::*;
+
+#[cfg(feature = "disposable")]
+mod disposable_domains;
+#[cfg(feature = "disposable")]
+pub use self::disposable_domains::*;
+
+mod domain;
+pub use self::domain::*;
+
 mod email;
 pub use self::email::*;
 
 mod email_error;
 pub use self::email_error::*;
 
+mod email_error_kind;
+pub use self::email_error_kind::*;
+
+mod email_ref;
+pub use self::email_ref::*;
+
+mod encoded_word;
+
+pub mod extract;
+
+mod format_address_header;
+pub use self::format_address_header::*;
+
+mod grammar;
+pub use self::grammar::*;
+
+mod idna_domain;
+
 mod is_valid_email;
 pub use self::is_valid_email::*;
+
+mod mailbox;
+pub use self::mailbox::*;
+
+mod mailto_uri;
+pub use self::mailto_uri::*;
+
+mod normalization_step;
+pub use self::normalization_step::*;
+
+mod normalized_email;
+pub use self::normalized_email::*;
+
+mod normalizer;
+pub use self::normalizer::*;
+
+mod parse_email;
+
+mod parse_mailbox;
+
+mod percent_encoding;
+
+#[cfg(feature = "public-suffix")]
+mod public_suffix;
+
+mod provider_rule;
+pub use self::provider_rule::*;
+
+mod provider_rules;
+pub use self::provider_rules::*;
+
+mod split_subaddress;
+
+mod validate_email;
+pub use self::validate_email::*;
+
+mod validation_mode;
+pub use self::validation_mode::*;
+
+mod validation_policy;
+pub use self::validation_policy::*;
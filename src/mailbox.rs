@@ -0,0 +1,368 @@
+use ::alloc::string::String;
+use ::alloc::string::ToString;
+use ::core::convert::AsRef;
+use ::core::convert::From;
+use ::core::fmt::Display;
+use ::core::fmt::Formatter;
+use ::core::fmt::Result as FmtResult;
+use ::core::str::FromStr;
+
+use crate::encoded_word::encode_encoded_words;
+use crate::parse_email::is_atext;
+use crate::parse_mailbox::parse_mailbox;
+use crate::Email;
+use crate::EmailError;
+use crate::ValidationPolicy;
+
+#[cfg(feature = "serde")]
+mod mailbox_visitor;
+#[cfg(feature = "serde")]
+pub(crate) use self::mailbox_visitor::*;
+
+#[cfg(feature = "serde")]
+mod serde_support;
+
+#[cfg(feature = "sea-orm")]
+mod sea_orm_support;
+
+/// An Email with an optional display name, as used in the `From` and `To` headers.
+/// i.e. `"Jane Doe" <jane@example.com>`.
+///
+/// These can be parsed using `Mailbox::from_string`, `Mailbox::from_str`, or `String::parse`.
+/// Parsing accepts quoted names, RFC 2047 encoded-words, and comments,
+/// as well as a bare email address on its own.
+///
+/// When displayed the name is quoted if it needs to be.
+/// Names holding control characters, such as a line break,
+/// are written as RFC 2047 encoded-words so they cannot break out of a header.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Mailbox {
+    display_name: Option<String>,
+    email: Email,
+}
+
+impl Mailbox {
+    /// Creates a new Mailbox, with a display name.
+    ///
+    /// An empty display name is the same as having no display name.
+    pub fn new<S>(display_name: S, email: Email) -> Self
+    where
+        S: Into<String>,
+    {
+        let display_name = display_name.into();
+
+        Self {
+            display_name: (!display_name.is_empty()).then_some(display_name),
+            email,
+        }
+    }
+
+    /// Creates a new Mailbox, from the `String` given.
+    ///
+    /// If the given string doesn't look like a valid mailbox,
+    /// then this will return an EmailError describing why.
+    pub fn from_string(raw_mailbox: String) -> Result<Self, EmailError> {
+        Self::from_string_with(raw_mailbox, ValidationPolicy::new())
+    }
+
+    /// Creates a new Mailbox, from the `String` given,
+    /// validating the email using the rules of the policy.
+    pub fn from_string_with<P>(raw_mailbox: String, policy: P) -> Result<Self, EmailError>
+    where
+        P: Into<ValidationPolicy>,
+    {
+        match parse_mailbox(&raw_mailbox, &policy.into()) {
+            Ok((display_name, email)) => Ok(Self {
+                display_name,
+                email,
+            }),
+            Err(kind) => Err(EmailError::Invalid {
+                raw_email: raw_mailbox,
+                kind,
+            }),
+        }
+    }
+
+    /// Creates a new Mailbox, from the `str` given.
+    ///
+    /// If the given string doesn't look like a valid mailbox,
+    /// then this will return an EmailError describing why.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str<S>(raw_mailbox: S) -> Result<Self, EmailError>
+    where
+        S: AsRef<str>,
+    {
+        Self::from_string(raw_mailbox.as_ref().to_string())
+    }
+
+    /// Creates a new Mailbox, from the `str` given,
+    /// validating the email using the rules of the policy.
+    pub fn from_str_with<S, P>(raw_mailbox: S, policy: P) -> Result<Self, EmailError>
+    where
+        S: AsRef<str>,
+        P: Into<ValidationPolicy>,
+    {
+        Self::from_string_with(raw_mailbox.as_ref().to_string(), policy)
+    }
+
+    pub fn display_name(&self) -> Option<&str> {
+        self.display_name.as_deref()
+    }
+
+    pub fn email(&self) -> &Email {
+        &self.email
+    }
+}
+
+impl Display for Mailbox {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.display_name {
+            Some(display_name) => {
+                write!(f, "{} <{}>", format_display_name(display_name), self.email)
+            }
+            None => write!(f, "{}", self.email),
+        }
+    }
+}
+
+/// Returns the display name as it is written before the email,
+/// which is quoted or encoded when needed.
+pub(crate) fn format_display_name(display_name: &str) -> String {
+    if has_control_characters(display_name) {
+        return encode_encoded_words(display_name).join(" ");
+    }
+
+    if is_plain_phrase(display_name) {
+        return display_name.to_string();
+    }
+
+    let mut quoted = String::with_capacity(display_name.len() + 2);
+    quoted.push('"');
+    for c in display_name.chars() {
+        if c == '"' || c == '\\' {
+            quoted.push('\\');
+        }
+        quoted.push(c);
+    }
+    quoted.push('"');
+
+    quoted
+}
+
+/// Returns if the name has characters which cannot be written into a header as they are,
+/// such as `\r` and `\n`.
+pub(crate) fn has_control_characters(display_name: &str) -> bool {
+    display_name.chars().any(char::is_control)
+}
+
+/// Returns if the name can be written without quotes,
+/// which is when it's made of words separated by single spaces.
+///
+/// Words which look like encoded-words, such as `=?UTF-8?B?SGk=?=`, are quoted,
+/// so they are not decoded when parsed back.
+fn is_plain_phrase(display_name: &str) -> bool {
+    display_name.split(' ').all(|word| {
+        !word.is_empty()
+            && word.chars().all(is_atext)
+            && !(word.starts_with("=?") && word.ends_with("?="))
+    })
+}
+
+impl From<Email> for Mailbox {
+    fn from(email: Email) -> Self {
+        Self {
+            display_name: None,
+            email,
+        }
+    }
+}
+
+impl From<Mailbox> for Email {
+    fn from(mailbox: Mailbox) -> Self {
+        mailbox.email
+    }
+}
+
+impl AsRef<Email> for Mailbox {
+    fn as_ref(&self) -> &Email {
+        &self.email
+    }
+}
+
+impl FromStr for Mailbox {
+    type Err = EmailError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Mailbox::from_str(s)
+    }
+}
+
+impl TryFrom<String> for Mailbox {
+    type Error = EmailError;
+
+    fn try_from(raw: String) -> Result<Self, Self::Error> {
+        Mailbox::from_string(raw)
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Mailbox {
+    type Error = EmailError;
+
+    fn try_from(raw: &'a str) -> Result<Self, Self::Error> {
+        Mailbox::from_str(raw)
+    }
+}
+
+#[cfg(test)]
+mod test_from_str {
+    use super::*;
+
+    #[test]
+    fn it_should_parse_a_quoted_name() {
+        let mailbox = Mailbox::from_str(r#""Jane Doe" <jane@example.com>"#).unwrap();
+
+        assert_eq!(mailbox.display_name(), Some("Jane Doe"));
+        assert_eq!(
+            mailbox.email(),
+            &Email::from_str("jane@example.com").unwrap()
+        );
+    }
+
+    #[test]
+    fn it_should_parse_a_bare_email() {
+        let mailbox = Mailbox::from_str("jane@example.com").unwrap();
+
+        assert_eq!(mailbox.display_name(), None);
+        assert_eq!(mailbox.email, "jane@example.com");
+    }
+
+    #[test]
+    fn it_should_not_accept_invalid_emails() {
+        let maybe_mailbox = Mailbox::from_str("Jane Doe <jane>");
+
+        assert!(maybe_mailbox.is_err());
+    }
+
+    #[test]
+    fn it_should_validate_with_the_policy() {
+        let policy = ValidationPolicy::new().require_tld(true);
+        let maybe_mailbox = Mailbox::from_str_with("Jane <jane@localhost>", policy);
+
+        assert!(maybe_mailbox.is_err());
+    }
+}
+
+#[cfg(test)]
+mod test_parse {
+    use super::*;
+
+    #[test]
+    fn it_should_parse_valid_mailbox_from_string() {
+        let mailbox: Mailbox = "Fox <fox@example.com>".parse().unwrap();
+
+        assert_eq!(
+            mailbox,
+            Mailbox::new("Fox", "fox@example.com".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn it_should_not_parse_invalid_mailbox_from_string() {
+        let maybe_mailbox: Result<Mailbox, EmailError> = "🦊 <🦊🦊🦊>".parse();
+
+        assert!(maybe_mailbox.is_err());
+    }
+}
+
+#[cfg(test)]
+mod test_display {
+    use super::*;
+
+    fn mailbox(display_name: &str) -> Mailbox {
+        Mailbox::new(display_name, "jane@example.com".parse().unwrap())
+    }
+
+    #[test]
+    fn it_should_write_just_the_email_without_a_name() {
+        let mailbox = Mailbox::from(Email::from_str("jane@example.com").unwrap());
+
+        assert_eq!(mailbox.to_string(), "jane@example.com");
+    }
+
+    #[test]
+    fn it_should_not_quote_plain_names() {
+        assert_eq!(
+            mailbox("Jane Doe").to_string(),
+            "Jane Doe <jane@example.com>"
+        );
+    }
+
+    #[test]
+    fn it_should_quote_names_with_special_characters() {
+        assert_eq!(
+            mailbox("Doe, Jane").to_string(),
+            r#""Doe, Jane" <jane@example.com>"#
+        );
+        assert_eq!(
+            mailbox("Jane Q. Doe").to_string(),
+            r#""Jane Q. Doe" <jane@example.com>"#
+        );
+    }
+
+    #[test]
+    fn it_should_escape_quotes_and_backslashes() {
+        assert_eq!(
+            mailbox(r#"Jane "JD" \ Doe"#).to_string(),
+            r#""Jane \"JD\" \\ Doe" <jane@example.com>"#
+        );
+    }
+
+    #[test]
+    fn it_should_quote_names_with_extra_spaces() {
+        assert_eq!(
+            mailbox(" Jane  Doe").to_string(),
+            r#"" Jane  Doe" <jane@example.com>"#
+        );
+    }
+
+    #[test]
+    fn it_should_parse_what_it_writes() {
+        let original = mailbox(r#"Doe, "JD" (Jane)"#);
+        let parsed: Mailbox = original.to_string().parse().unwrap();
+
+        assert_eq!(parsed, original);
+    }
+
+    #[test]
+    fn it_should_quote_names_which_look_like_encoded_words() {
+        let original = mailbox("=?UTF-8?B?SGk=?=");
+        let parsed: Mailbox = original.to_string().parse().unwrap();
+
+        assert_eq!(
+            original.to_string(),
+            r#""=?UTF-8?B?SGk=?=" <jane@example.com>"#
+        );
+        assert_eq!(parsed.display_name(), Some("=?UTF-8?B?SGk=?="));
+    }
+
+    #[test]
+    fn it_should_encode_names_with_line_breaks() {
+        let original = mailbox("Evil\r\nBcc: victim@x.com");
+
+        assert_eq!(
+            original.to_string(),
+            "=?UTF-8?B?RXZpbA0KQmNjOiB2aWN0aW1AeC5jb20=?= <jane@example.com>"
+        );
+        assert_eq!(original.to_string().parse::<Mailbox>().unwrap(), original);
+    }
+
+    #[test]
+    fn it_should_write_just_the_email_with_an_empty_name() {
+        let original = mailbox("");
+        let parsed: Mailbox = original.to_string().parse().unwrap();
+
+        assert_eq!(original.display_name(), None);
+        assert_eq!(original.to_string(), "jane@example.com");
+        assert_eq!(parsed, original);
+    }
+}
@@ -0,0 +1,38 @@
+use ::alloc::format;
+use ::alloc::string::String;
+use ::core::fmt::Formatter;
+use ::core::fmt::Result as FmtResult;
+use ::serde::de::Error as SerdeDeError;
+use ::serde::de::Visitor;
+
+use crate::Mailbox;
+
+pub struct MailboxVisitor;
+
+impl<'de> Visitor<'de> for MailboxVisitor {
+    type Value = Mailbox;
+
+    fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+        formatter.write_str("a valid mailbox")
+    }
+
+    fn visit_str<E>(self, raw_mailbox: &str) -> Result<Self::Value, E>
+    where
+        E: SerdeDeError,
+    {
+        Mailbox::from_str(raw_mailbox).map_err(|err| {
+            let msg = format!("{}", err);
+            SerdeDeError::custom(msg)
+        })
+    }
+
+    fn visit_string<E>(self, raw_mailbox: String) -> Result<Self::Value, E>
+    where
+        E: SerdeDeError,
+    {
+        Mailbox::from_string(raw_mailbox).map_err(|err| {
+            let msg = format!("{}", err);
+            SerdeDeError::custom(msg)
+        })
+    }
+}
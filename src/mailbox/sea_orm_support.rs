@@ -0,0 +1,167 @@
+use ::core::convert::From;
+use ::sea_orm::entity::ActiveValue;
+use ::sea_orm::entity::IntoActiveValue;
+use ::sea_orm::error::DbErr;
+use ::sea_orm::sea_query::table::ColumnType;
+use ::sea_orm::sea_query::value::ArrayType;
+use ::sea_orm::sea_query::value::Nullable;
+use ::sea_orm::sea_query::value::ValueType;
+use ::sea_orm::sea_query::value::ValueTypeErr;
+use ::sea_orm::ColIdx;
+use ::sea_orm::QueryResult;
+use ::sea_orm::TryGetError;
+use ::sea_orm::TryGetable;
+use ::sea_orm::Value;
+
+use crate::Mailbox;
+
+impl From<Mailbox> for Value {
+    fn from(mailbox: Mailbox) -> Value {
+        Value::String(Some(Box::new(mailbox.to_string())))
+    }
+}
+
+impl Nullable for Mailbox {
+    fn null() -> Value {
+        Value::String(None)
+    }
+}
+
+impl TryGetable for Mailbox {
+    fn try_get_by<I>(res: &QueryResult, index: I) -> Result<Self, TryGetError>
+    where
+        I: ColIdx,
+    {
+        res.try_get_by::<Option<String>, I>(index)
+            .map_err(TryGetError::DbErr)
+            .and_then(|maybe_raw| match maybe_raw {
+                Some(raw) => Mailbox::from_string(raw).map_err(|err| {
+                    let db_err = DbErr::Custom(err.to_string());
+                    TryGetError::DbErr(db_err)
+                }),
+                None => Err(TryGetError::Null(format!("{index:?}"))),
+            })
+    }
+
+    fn try_get(res: &QueryResult, pre: &str, col: &str) -> Result<Self, TryGetError> {
+        res.try_get::<Option<String>>(pre, col)
+            .map_err(TryGetError::DbErr)
+            .and_then(|maybe_raw| match maybe_raw {
+                Some(raw) => Mailbox::from_string(raw).map_err(|err| {
+                    let db_err = DbErr::Custom(err.to_string());
+                    TryGetError::DbErr(db_err)
+                }),
+                None => Err(TryGetError::Null(col.to_string())),
+            })
+    }
+}
+
+impl ValueType for Mailbox {
+    fn try_from(value: Value) -> Result<Self, ValueTypeErr> {
+        match value {
+            Value::String(Some(raw_mailbox)) => {
+                Mailbox::from_string(*raw_mailbox).map_err(|_| ValueTypeErr)
+            }
+            _ => Err(ValueTypeErr),
+        }
+    }
+
+    fn type_name() -> String {
+        "Mailbox".to_string()
+    }
+
+    fn array_type() -> ArrayType {
+        ArrayType::String
+    }
+
+    fn column_type() -> ColumnType {
+        ColumnType::Text
+    }
+}
+
+impl IntoActiveValue<Mailbox> for Mailbox {
+    fn into_active_value(self) -> ActiveValue<Self> {
+        ActiveValue::Set(self)
+    }
+}
+
+#[cfg(test)]
+mod test_try_getable {
+    use super::*;
+    use ::sea_orm::entity::prelude::*;
+
+    #[test]
+    fn it_should_compile_with_mailbox() {
+        #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+        #[sea_orm(table_name = "test")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            pub mailbox: Mailbox,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+
+        let mailbox = Mailbox::from_str("Jane Doe <jane@example.com>").unwrap();
+        let model = Model {
+            id: 123,
+            mailbox: mailbox.clone(),
+        };
+
+        // If it reaches this point, it means the above compiled fine.
+        assert_eq!(model.mailbox, mailbox);
+    }
+
+    #[test]
+    fn it_should_compile_with_optional_mailbox_as_none() {
+        #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+        #[sea_orm(table_name = "test")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            pub mailbox: Option<Mailbox>,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+
+        let mailbox = None;
+        let model = Model {
+            id: 123,
+            mailbox: mailbox.clone(),
+        };
+
+        // If it reaches this point, it means the above compiled fine.
+        assert_eq!(model.mailbox, mailbox);
+    }
+
+    #[test]
+    fn it_should_compile_with_optional_some_mailbox() {
+        #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+        #[sea_orm(table_name = "test")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            pub mailbox: Option<Mailbox>,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+
+        let mailbox = Some(Mailbox::from_str("Jane Doe <jane@example.com>").unwrap());
+        let model = Model {
+            id: 123,
+            mailbox: mailbox.clone(),
+        };
+
+        // If it reaches this point, it means the above compiled fine.
+        assert_eq!(model.mailbox, mailbox);
+    }
+}
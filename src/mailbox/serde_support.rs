@@ -0,0 +1,64 @@
+use ::serde::Deserialize;
+use ::serde::Deserializer;
+use ::serde::Serialize;
+use ::serde::Serializer;
+
+use crate::Mailbox;
+use crate::MailboxVisitor;
+
+impl Serialize for Mailbox {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Mailbox {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_string(MailboxVisitor)
+    }
+}
+
+#[cfg(test)]
+mod test_serialisation {
+    use super::*;
+    use ::serde_json;
+
+    #[test]
+    fn it_should_serialise_mailbox_to_string() {
+        let mailbox = Mailbox::from_str(r#""Doe, John" <john@example.com>"#).unwrap();
+        let raw = serde_json::to_string(&mailbox).unwrap();
+
+        assert_eq!(raw, r#""\"Doe, John\" <john@example.com>""#);
+    }
+}
+
+#[cfg(test)]
+mod test_deserialisation {
+    use super::*;
+    use ::serde_json;
+
+    #[test]
+    fn it_should_deserialise_mailbox_from_string() {
+        let raw_json_mailbox = r#""John Doe <john@example.com>""#;
+
+        let mailbox: Mailbox = serde_json::from_str(raw_json_mailbox).unwrap();
+
+        assert_eq!(
+            mailbox,
+            Mailbox::from_str("John Doe <john@example.com>").unwrap()
+        );
+    }
+
+    #[test]
+    fn it_should_not_deserialise_non_mailbox_from_string() {
+        let result = serde_json::from_str::<Mailbox>(r#""John Doe <donkeys>""#);
+
+        assert!(result.is_err());
+    }
+}
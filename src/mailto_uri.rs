@@ -0,0 +1,376 @@
+use ::alloc::format;
+use ::alloc::string::String;
+use ::alloc::vec::Vec;
+use ::core::fmt::Display;
+use ::core::fmt::Formatter;
+use ::core::fmt::Result as FmtResult;
+
+use crate::percent_encoding::is_unreserved;
+use crate::percent_encoding::percent_decode;
+use crate::percent_encoding::percent_encode;
+use crate::Email;
+use crate::EmailError;
+
+/// A `mailto:` URI, as described in RFC 6068.
+/// i.e. `mailto:john@example.com?cc=jane@example.com&subject=Hello`.
+///
+/// It can be built up from `Email`s, and written out using `Display`,
+/// with every part percent encoded.
+///
+/// ```rust
+/// use ::serde_email::Email;
+///
+/// let email = Email::from_str("john@example.com").unwrap();
+/// let uri = email.to_mailto_uri()
+///     .with_cc(Email::from_str("jane@example.com").unwrap())
+///     .with_subject("Hello & welcome");
+///
+/// assert_eq!(
+///     uri.to_string(),
+///     "mailto:john@example.com?cc=jane@example.com&subject=Hello%20%26%20welcome",
+/// );
+/// ```
+///
+/// Or parsed from a link, where each recipient is validated on its own.
+///
+/// ```rust
+/// use ::serde_email::MailtoUri;
+///
+/// let uri = MailtoUri::parse("mailto:john@example.com,nope?subject=Hi").unwrap();
+///
+/// assert_eq!(uri.emails().count(), 1);
+/// assert_eq!(uri.errors().count(), 1);
+/// assert_eq!(uri.subject(), Some("Hi"));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MailtoUri {
+    to: Vec<Result<Email, EmailError>>,
+    cc: Vec<Result<Email, EmailError>>,
+    bcc: Vec<Result<Email, EmailError>>,
+    subject: Option<String>,
+    body: Option<String>,
+}
+
+impl MailtoUri {
+    /// Creates a `mailto:` URI with no recipients.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses a `mailto:` URI.
+    ///
+    /// Returns `None` if the URI does not use the `mailto` scheme.
+    /// Recipients are found in the path, and in `to`, `cc`, and `bcc` fields.
+    /// Other header fields are ignored.
+    pub fn parse<S>(raw: S) -> Option<Self>
+    where
+        S: AsRef<str>,
+    {
+        let raw = raw.as_ref().trim();
+        let scheme_end = raw.find(':')?;
+        if !raw[..scheme_end].eq_ignore_ascii_case("mailto") {
+            return None;
+        }
+
+        let uri = &raw[scheme_end + 1..];
+        let uri = uri.split_once('#').map_or(uri, |(uri, _fragment)| uri);
+        let (path, query) = uri.split_once('?').unwrap_or((uri, ""));
+
+        let mut mailto = Self::new();
+        mailto.to.extend(parse_recipients(path));
+
+        for field in query.split('&').filter(|field| !field.is_empty()) {
+            let (name, value) = field.split_once('=').unwrap_or((field, ""));
+
+            match percent_decode(name).to_ascii_lowercase().as_str() {
+                "to" => mailto.to.extend(parse_recipients(value)),
+                "cc" => mailto.cc.extend(parse_recipients(value)),
+                "bcc" => mailto.bcc.extend(parse_recipients(value)),
+                "subject" => mailto.subject = Some(percent_decode(value)),
+                "body" => mailto.body = Some(percent_decode(value)),
+                _ => {}
+            }
+        }
+
+        Some(mailto)
+    }
+
+    pub fn with_to(mut self, email: Email) -> Self {
+        self.to.push(Ok(email));
+        self
+    }
+
+    pub fn with_cc(mut self, email: Email) -> Self {
+        self.cc.push(Ok(email));
+        self
+    }
+
+    pub fn with_bcc(mut self, email: Email) -> Self {
+        self.bcc.push(Ok(email));
+        self
+    }
+
+    pub fn with_subject<S>(mut self, subject: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.subject = Some(subject.into());
+        self
+    }
+
+    /// Sets the body. Line breaks are written as `\r\n`, as RFC 6068 requires.
+    pub fn with_body<S>(mut self, body: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.body = Some(body.into());
+        self
+    }
+
+    pub fn to(&self) -> &[Result<Email, EmailError>] {
+        &self.to
+    }
+
+    pub fn cc(&self) -> &[Result<Email, EmailError>] {
+        &self.cc
+    }
+
+    pub fn bcc(&self) -> &[Result<Email, EmailError>] {
+        &self.bcc
+    }
+
+    pub fn subject(&self) -> Option<&str> {
+        self.subject.as_deref()
+    }
+
+    pub fn body(&self) -> Option<&str> {
+        self.body.as_deref()
+    }
+
+    /// Returns every recipient, from `to`, `cc`, and `bcc`.
+    pub fn recipients(&self) -> impl Iterator<Item = &Result<Email, EmailError>> {
+        self.to.iter().chain(&self.cc).chain(&self.bcc)
+    }
+
+    /// Returns every valid recipient.
+    pub fn emails(&self) -> impl Iterator<Item = &Email> {
+        self.recipients().filter_map(|email| email.as_ref().ok())
+    }
+
+    /// Returns the errors for every invalid recipient.
+    pub fn errors(&self) -> impl Iterator<Item = &EmailError> {
+        self.recipients().filter_map(|email| email.as_ref().err())
+    }
+
+    /// Returns true if every recipient is valid.
+    pub fn is_valid(&self) -> bool {
+        self.errors().next().is_none()
+    }
+}
+
+/// Invalid recipients are skipped when writing the URI.
+impl Display for MailtoUri {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "mailto:{}", encode_recipients(&self.to))?;
+
+        let mut fields = Vec::new();
+        if self.cc.iter().any(Result::is_ok) {
+            fields.push(format!("cc={}", encode_recipients(&self.cc)));
+        }
+        if self.bcc.iter().any(Result::is_ok) {
+            fields.push(format!("bcc={}", encode_recipients(&self.bcc)));
+        }
+        if let Some(subject) = &self.subject {
+            fields.push(format!("subject={}", percent_encode(subject, is_hfvalue)));
+        }
+        if let Some(body) = &self.body {
+            let body = body.replace("\r\n", "\n").replace('\n', "\r\n");
+            fields.push(format!("body={}", percent_encode(&body, is_hfvalue)));
+        }
+
+        if !fields.is_empty() {
+            write!(f, "?{}", fields.join("&"))?;
+        }
+
+        Ok(())
+    }
+}
+
+fn parse_recipients(raw: &str) -> impl Iterator<Item = Result<Email, EmailError>> + '_ {
+    raw.split(',')
+        .map(percent_decode)
+        .filter(|recipient| !recipient.trim().is_empty())
+        .map(Email::from_string)
+}
+
+fn encode_recipients(recipients: &[Result<Email, EmailError>]) -> String {
+    recipients
+        .iter()
+        .filter_map(|email| email.as_ref().ok())
+        .map(|email| percent_encode(email.as_str(), is_addr_spec))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// The characters left unencoded in an address.
+/// The `,` is encoded as it separates addresses.
+fn is_addr_spec(byte: u8) -> bool {
+    is_unreserved(byte)
+        || matches!(
+            byte,
+            b'!' | b'$' | b'\'' | b'(' | b')' | b'*' | b'+' | b';' | b':' | b'@'
+        )
+}
+
+/// The characters left unencoded in a header field value, such as the subject.
+fn is_hfvalue(byte: u8) -> bool {
+    is_unreserved(byte)
+        || matches!(
+            byte,
+            b'!' | b'$' | b'\'' | b'(' | b')' | b'*' | b'+' | b',' | b';' | b':' | b'@'
+        )
+}
+
+#[cfg(test)]
+mod test_to_mailto_uri {
+    use super::*;
+
+    fn email(raw: &str) -> Email {
+        Email::from_str(raw).unwrap()
+    }
+
+    #[test]
+    fn it_should_write_the_email() {
+        let uri = email("john@example.com").to_mailto_uri();
+
+        assert_eq!(uri.to_string(), "mailto:john@example.com");
+    }
+
+    #[test]
+    fn it_should_write_multiple_recipients() {
+        let uri = email("a@x.com")
+            .to_mailto_uri()
+            .with_to(email("b@y.com"))
+            .with_cc(email("c@z.com"))
+            .with_bcc(email("d@z.com"));
+
+        assert_eq!(
+            uri.to_string(),
+            "mailto:a@x.com,b@y.com?cc=c@z.com&bcc=d@z.com"
+        );
+    }
+
+    #[test]
+    fn it_should_encode_the_subject_and_body() {
+        let uri = email("john@example.com")
+            .to_mailto_uri()
+            .with_subject("Tea & cake?")
+            .with_body("Hi,\nSee you at 3 = 15:00 #tea");
+
+        assert_eq!(
+            uri.to_string(),
+            "mailto:john@example.com?subject=Tea%20%26%20cake%3F&body=Hi,%0D%0ASee%20you%20at%203%20%3D%2015:00%20%23tea"
+        );
+    }
+
+    #[test]
+    fn it_should_encode_special_characters_in_addresses() {
+        let uri = email(r#""john,doe"@example.com"#).to_mailto_uri();
+
+        assert_eq!(uri.to_string(), "mailto:%22john%2Cdoe%22@example.com");
+    }
+
+    #[test]
+    fn it_should_encode_non_ascii() {
+        let uri = email("jöhn@example.com")
+            .to_mailto_uri()
+            .with_subject("Grüße");
+
+        assert_eq!(
+            uri.to_string(),
+            "mailto:j%C3%B6hn@example.com?subject=Gr%C3%BC%C3%9Fe"
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_parse {
+    use super::*;
+
+    #[test]
+    fn it_should_parse_the_path() {
+        let uri = MailtoUri::parse("mailto:a@x.com,b@y.com").unwrap();
+
+        assert_eq!(uri.to().len(), 2);
+        assert_eq!(
+            uri.emails().map(Email::as_str).collect::<Vec<_>>(),
+            vec!["a@x.com", "b@y.com"]
+        );
+    }
+
+    #[test]
+    fn it_should_parse_header_fields() {
+        let uri = MailtoUri::parse(
+            "MAILTO:?to=a@x.com&Cc=c@z.com&bcc=d@z.com&subject=Hello%20there&body=Line%0D%0ALine&x-other=1",
+        )
+        .unwrap();
+
+        assert_eq!(uri.to().len(), 1);
+        assert_eq!(uri.cc().len(), 1);
+        assert_eq!(uri.bcc().len(), 1);
+        assert_eq!(uri.subject(), Some("Hello there"));
+        assert_eq!(uri.body(), Some("Line\r\nLine"));
+    }
+
+    #[test]
+    fn it_should_decode_addresses() {
+        let uri = MailtoUri::parse("mailto:%22john%2Cdoe%22@example.com").unwrap();
+
+        assert_eq!(
+            uri.emails().map(Email::as_str).collect::<Vec<_>>(),
+            vec![r#""john,doe"@example.com"#]
+        );
+    }
+
+    #[test]
+    fn it_should_report_invalid_recipients() {
+        let uri = MailtoUri::parse("mailto:a@x.com,nope?cc=@z.com").unwrap();
+
+        assert!(!uri.is_valid());
+        assert_eq!(uri.emails().count(), 1);
+
+        let errors: Vec<_> = uri
+            .errors()
+            .map(|EmailError::Invalid { raw_email, .. }| raw_email.as_str())
+            .collect();
+        assert_eq!(errors, vec!["nope", "@z.com"]);
+    }
+
+    #[test]
+    fn it_should_ignore_the_fragment() {
+        let uri = MailtoUri::parse("mailto:a@x.com#top").unwrap();
+
+        assert_eq!(
+            uri.emails().map(Email::as_str).collect::<Vec<_>>(),
+            vec!["a@x.com"]
+        );
+    }
+
+    #[test]
+    fn it_should_reject_other_schemes() {
+        assert_eq!(MailtoUri::parse("https://example.com"), None);
+        assert_eq!(MailtoUri::parse("john@example.com"), None);
+    }
+
+    #[test]
+    fn it_should_round_trip() {
+        let uri = Email::from_str(r#""a b"@x.com"#)
+            .unwrap()
+            .to_mailto_uri()
+            .with_cc(Email::from_str("c@z.com").unwrap())
+            .with_subject("Tea & cake?")
+            .with_body("Hi\r\nthere");
+
+        assert_eq!(MailtoUri::parse(uri.to_string()), Some(uri));
+    }
+}
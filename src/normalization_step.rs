@@ -0,0 +1,23 @@
+/// A single step in normalising an email, used by a `Normalizer`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NormalizationStep {
+    /// Removes whitespace from the start and end of the address, before it is validated.
+    Trim,
+
+    /// Lowercases the domain. The local part is left alone.
+    LowercaseDomain,
+
+    /// Converts an internationalised domain to ASCII, using the UTS #46 IDNA mapping.
+    /// i.e. `bücher.de` becomes `xn--bcher-kva.de`.
+    IdnaToAscii,
+
+    /// Applies Unicode Normalization Form C to the local part.
+    /// This makes characters which look the same, be the same.
+    NfcLocalPart,
+
+    /// Lowercases the whole address, including the local part.
+    ///
+    /// Mail servers are allowed to treat local parts as case sensitive,
+    /// so only use this if you know yours does not.
+    LowercaseAll,
+}
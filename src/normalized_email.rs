@@ -0,0 +1,94 @@
+use ::alloc::vec::Vec;
+use ::core::convert::AsRef;
+use ::core::convert::From;
+use ::core::fmt::Display;
+use ::core::fmt::Formatter;
+use ::core::fmt::Result as FmtResult;
+use ::core::hash::Hash;
+use ::core::hash::Hasher;
+use ::core::ops::Deref;
+
+use crate::Email;
+use crate::NormalizationStep;
+
+/// An Email which has been passed through a `Normalizer`.
+///
+/// Two NormalizedEmails are equal if their normalised addresses are equal,
+/// regardless of which steps were needed to get there.
+/// This makes them suitable as keys for finding duplicates.
+#[derive(Clone, Debug)]
+pub struct NormalizedEmail {
+    email: Email,
+    applied_steps: Vec<NormalizationStep>,
+}
+
+impl NormalizedEmail {
+    pub(crate) fn new(email: Email, applied_steps: Vec<NormalizationStep>) -> Self {
+        Self {
+            email,
+            applied_steps,
+        }
+    }
+
+    pub fn as_email(&self) -> &Email {
+        &self.email
+    }
+
+    pub fn into_email(self) -> Email {
+        self.email
+    }
+
+    /// Returns the steps which changed the address, in the order they were applied.
+    ///
+    /// Steps which were part of the `Normalizer`,
+    /// but made no difference to this address, are not included.
+    pub fn applied_steps(&self) -> &[NormalizationStep] {
+        &self.applied_steps
+    }
+}
+
+impl Deref for NormalizedEmail {
+    type Target = Email;
+
+    fn deref(&self) -> &Email {
+        &self.email
+    }
+}
+
+impl PartialEq for NormalizedEmail {
+    fn eq(&self, other: &Self) -> bool {
+        self.email == other.email
+    }
+}
+
+impl Eq for NormalizedEmail {}
+
+impl Hash for NormalizedEmail {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.email.hash(state);
+    }
+}
+
+impl Display for NormalizedEmail {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.email)
+    }
+}
+
+impl From<NormalizedEmail> for Email {
+    fn from(email: NormalizedEmail) -> Self {
+        email.email
+    }
+}
+
+impl AsRef<str> for NormalizedEmail {
+    fn as_ref(&self) -> &str {
+        self.email.as_str()
+    }
+}
+
+impl AsRef<Email> for NormalizedEmail {
+    fn as_ref(&self) -> &Email {
+        &self.email
+    }
+}
@@ -0,0 +1,301 @@
+use ::alloc::format;
+use ::alloc::string::String;
+use ::alloc::string::ToString;
+use ::alloc::vec::Vec;
+use ::core::convert::AsRef;
+use ::unicode_normalization::UnicodeNormalization;
+
+use crate::idna_domain::domain_to_ascii_at;
+use crate::Email;
+use crate::EmailError;
+use crate::NormalizationStep;
+use crate::NormalizedEmail;
+use crate::ValidationPolicy;
+
+/// A pipeline of steps for turning email addresses into a canonical form.
+///
+/// `Trim` is always run first, before the address is validated, wherever it was added.
+/// The other steps are then run in the order they were added.
+/// The result is validated again at the end,
+/// so a `NormalizedEmail` is always a valid Email.
+///
+/// ```rust
+/// use ::serde_email::NormalizationStep;
+/// use ::serde_email::Normalizer;
+///
+/// let normalizer = Normalizer::new();
+/// let email = normalizer.normalize(" John@Bücher.DE ").unwrap();
+///
+/// assert_eq!(email.as_str(), "John@xn--bcher-kva.de");
+/// assert_eq!(
+///     email.applied_steps(),
+///     &[NormalizationStep::Trim, NormalizationStep::LowercaseDomain, NormalizationStep::IdnaToAscii],
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Normalizer {
+    steps: Vec<NormalizationStep>,
+    policy: ValidationPolicy,
+}
+
+impl Normalizer {
+    /// Creates a Normalizer with the recommended steps.
+    ///
+    /// These are `Trim`, `NfcLocalPart`, `LowercaseDomain`, and then `IdnaToAscii`.
+    /// The local part is never lowercased.
+    pub fn new() -> Self {
+        Self::empty()
+            .with_step(NormalizationStep::Trim)
+            .with_step(NormalizationStep::NfcLocalPart)
+            .with_step(NormalizationStep::LowercaseDomain)
+            .with_step(NormalizationStep::IdnaToAscii)
+    }
+
+    /// Creates a Normalizer with no steps.
+    pub fn empty() -> Self {
+        Self {
+            steps: Vec::new(),
+            policy: ValidationPolicy::new(),
+        }
+    }
+
+    /// Adds a step to the end of the pipeline.
+    pub fn with_step(mut self, step: NormalizationStep) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    /// Sets the policy used for validating addresses, before and after normalising.
+    pub fn with_policy<P>(self, policy: P) -> Self
+    where
+        P: Into<ValidationPolicy>,
+    {
+        Self {
+            policy: policy.into(),
+            ..self
+        }
+    }
+
+    pub fn steps(&self) -> &[NormalizationStep] {
+        &self.steps
+    }
+
+    /// Validates and normalises the given string.
+    pub fn normalize<S>(&self, raw: S) -> Result<NormalizedEmail, EmailError>
+    where
+        S: AsRef<str>,
+    {
+        let raw = raw.as_ref();
+        let mut applied_steps = Vec::new();
+
+        let raw = if self.steps.contains(&NormalizationStep::Trim) {
+            let trimmed = raw.trim();
+            if trimmed.len() != raw.len() {
+                applied_steps.push(NormalizationStep::Trim);
+            }
+            trimmed
+        } else {
+            raw
+        };
+
+        let email = Email::from_str_with(raw, self.policy)?;
+        self.apply_steps(&email, applied_steps)
+    }
+
+    /// Normalises an Email which has already been validated.
+    pub fn normalize_email(&self, email: &Email) -> Result<NormalizedEmail, EmailError> {
+        self.apply_steps(email, Vec::new())
+    }
+
+    fn apply_steps(
+        &self,
+        email: &Email,
+        mut applied_steps: Vec<NormalizationStep>,
+    ) -> Result<NormalizedEmail, EmailError> {
+        let mut local_part = email.local_part().to_string();
+        let mut domain = email.domain().to_string();
+
+        for &step in &self.steps {
+            let is_changed = match step {
+                NormalizationStep::Trim => false,
+                NormalizationStep::LowercaseDomain => {
+                    let lowercase_domain = domain.to_lowercase();
+                    replace_if_changed(&mut domain, lowercase_domain)
+                }
+                NormalizationStep::IdnaToAscii => {
+                    let domain_start = local_part.len() + 1;
+                    let ascii_domain =
+                        domain_to_ascii_at(&domain, domain_start).map_err(|kind| {
+                            EmailError::Invalid {
+                                raw_email: format!("{local_part}@{domain}"),
+                                kind,
+                            }
+                        })?;
+
+                    replace_if_changed(&mut domain, ascii_domain)
+                }
+                NormalizationStep::NfcLocalPart => {
+                    let nfc_local_part = local_part.nfc().collect();
+                    replace_if_changed(&mut local_part, nfc_local_part)
+                }
+                NormalizationStep::LowercaseAll => {
+                    let lowercase_local_part = local_part.to_lowercase();
+                    let lowercase_domain = domain.to_lowercase();
+                    let is_local_part_changed =
+                        replace_if_changed(&mut local_part, lowercase_local_part);
+                    let is_domain_changed = replace_if_changed(&mut domain, lowercase_domain);
+
+                    is_local_part_changed || is_domain_changed
+                }
+            };
+
+            if is_changed && !applied_steps.contains(&step) {
+                applied_steps.push(step);
+            }
+        }
+
+        let email = Email::from_string_with(format!("{local_part}@{domain}"), self.policy)?;
+        Ok(NormalizedEmail::new(email, applied_steps))
+    }
+}
+
+/// Same as `Normalizer::new`.
+impl Default for Normalizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn replace_if_changed(value: &mut String, new_value: String) -> bool {
+    if *value == new_value {
+        return false;
+    }
+
+    *value = new_value;
+    true
+}
+
+#[cfg(test)]
+mod test_normalize {
+    use super::*;
+    use crate::EmailErrorKind;
+
+    #[test]
+    fn it_should_trim_whitespace() {
+        let email = Normalizer::new().normalize("  john@example.com\n").unwrap();
+
+        assert_eq!(email.as_str(), "john@example.com");
+        assert_eq!(email.applied_steps(), &[NormalizationStep::Trim]);
+    }
+
+    #[test]
+    fn it_should_trim_first_when_added_last() {
+        let email = Normalizer::empty()
+            .with_step(NormalizationStep::LowercaseDomain)
+            .with_step(NormalizationStep::Trim)
+            .normalize(" john@EXAMPLE.com ")
+            .unwrap();
+
+        assert_eq!(email.as_str(), "john@example.com");
+        assert_eq!(
+            email.applied_steps(),
+            &[NormalizationStep::Trim, NormalizationStep::LowercaseDomain]
+        );
+    }
+
+    #[test]
+    fn it_should_not_trim_without_the_step() {
+        let maybe_email = Normalizer::empty().normalize("  john@example.com");
+
+        assert!(maybe_email.is_err());
+    }
+
+    #[test]
+    fn it_should_lowercase_the_domain_only() {
+        let email = Normalizer::new().normalize("John@EXAMPLE.com").unwrap();
+
+        assert_eq!(email.as_str(), "John@example.com");
+        assert_eq!(email.applied_steps(), &[NormalizationStep::LowercaseDomain]);
+    }
+
+    #[test]
+    fn it_should_convert_the_domain_to_ascii() {
+        let email = Normalizer::empty()
+            .with_step(NormalizationStep::IdnaToAscii)
+            .normalize("john@bücher.de")
+            .unwrap();
+
+        assert_eq!(email.as_str(), "john@xn--bcher-kva.de");
+        assert_eq!(email.applied_steps(), &[NormalizationStep::IdnaToAscii]);
+    }
+
+    #[test]
+    fn it_should_compose_the_local_part() {
+        let decomposed = "jo\u{0301}se@example.com";
+        let email = Normalizer::new().normalize(decomposed).unwrap();
+
+        assert_eq!(email.as_str(), "jóse@example.com");
+        assert_eq!(email.applied_steps(), &[NormalizationStep::NfcLocalPart]);
+    }
+
+    #[test]
+    fn it_should_lowercase_everything() {
+        let email = Normalizer::empty()
+            .with_step(NormalizationStep::LowercaseAll)
+            .normalize("John@EXAMPLE.com")
+            .unwrap();
+
+        assert_eq!(email.as_str(), "john@example.com");
+    }
+
+    #[test]
+    fn it_should_record_no_steps_for_normalised_addresses() {
+        let email = Normalizer::new().normalize("john@example.com").unwrap();
+
+        assert!(email.applied_steps().is_empty());
+    }
+
+    #[test]
+    fn it_should_make_equal_keys_for_equivalent_addresses() {
+        let normalizer = Normalizer::new();
+        let a = normalizer.normalize(" jo\u{0301}se@BÜCHER.de").unwrap();
+        let b = normalizer.normalize("jóse@xn--bcher-kva.de").unwrap();
+
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn it_should_return_idna_errors() {
+        let err = Normalizer::new()
+            .normalize("john@example.xn--a.com")
+            .unwrap_err();
+
+        assert_eq!(
+            err.kind(),
+            &EmailErrorKind::InvalidIdnaLabel { position: 13 }
+        );
+    }
+
+    #[test]
+    fn it_should_validate_with_the_policy() {
+        let policy = ValidationPolicy::new().require_tld(true);
+        let maybe_email = Normalizer::new()
+            .with_policy(policy)
+            .normalize("john@localhost");
+
+        assert!(maybe_email.is_err());
+    }
+}
+
+#[cfg(test)]
+mod test_normalize_email {
+    use super::*;
+
+    #[test]
+    fn it_should_normalise_existing_emails() {
+        let email = Email::from_str("John@Example.COM").unwrap();
+        let normalized = Normalizer::new().normalize_email(&email).unwrap();
+
+        assert_eq!(normalized.as_str(), "John@example.com");
+    }
+}
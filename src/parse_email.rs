@@ -0,0 +1,707 @@
+use ::core::net::Ipv4Addr;
+use ::core::net::Ipv6Addr;
+
+#[cfg(feature = "disposable")]
+use crate::disposable_domains::is_built_in_disposable_domain;
+#[cfg(feature = "public-suffix")]
+use crate::public_suffix::is_known_tld;
+use crate::Domain;
+use crate::EmailErrorKind;
+use crate::Grammar;
+use crate::ValidationPolicy;
+
+const LABEL_MAX_LENGTH: usize = 63;
+
+/// Parses the email address given, using the grammar and rules of the policy.
+///
+/// On success this returns the byte index of the `@`
+/// which separates the local part from the domain.
+pub(crate) fn parse_email(raw: &str, policy: &ValidationPolicy) -> Result<usize, EmailErrorKind> {
+    if !policy.allow_utf8 {
+        if let Some((position, character)) = raw.char_indices().find(|(_, c)| !c.is_ascii()) {
+            return Err(EmailErrorKind::InvalidCharacter {
+                character,
+                position,
+            });
+        }
+    }
+
+    let at_index = parse_local_part(raw, policy)?;
+    parse_domain(raw, at_index + 1, policy)?;
+
+    if raw.len() > policy.max_length {
+        return Err(EmailErrorKind::AddressTooLong {
+            length: raw.len(),
+            max: policy.max_length,
+        });
+    }
+
+    Ok(at_index)
+}
+
+fn parse_local_part(raw: &str, policy: &ValidationPolicy) -> Result<usize, EmailErrorKind> {
+    let at_index = if policy.grammar == Grammar::Html5 {
+        parse_html5_local_part(raw)?
+    } else if raw.starts_with('"') {
+        if !policy.allow_quoted_local_parts {
+            return Err(EmailErrorKind::QuotedLocalPartNotAllowed);
+        }
+
+        parse_quoted_local_part(raw, policy.grammar)?
+    } else {
+        parse_dot_atom_local_part(raw)?
+    };
+
+    if !policy.allow_utf8_local_part {
+        if let Some((position, character)) =
+            raw[..at_index].char_indices().find(|(_, c)| !c.is_ascii())
+        {
+            return Err(EmailErrorKind::InvalidCharacter {
+                character,
+                position,
+            });
+        }
+    }
+
+    if at_index > policy.max_local_part_length {
+        return Err(EmailErrorKind::LocalPartTooLong {
+            length: at_index,
+            max: policy.max_local_part_length,
+        });
+    }
+
+    Ok(at_index)
+}
+
+fn parse_dot_atom_local_part(raw: &str) -> Result<usize, EmailErrorKind> {
+    let mut previous = None;
+
+    for (position, character) in raw.char_indices() {
+        match character {
+            '@' if position == 0 => return Err(EmailErrorKind::LocalPartEmpty),
+            '@' if previous == Some('.') => {
+                return Err(EmailErrorKind::InvalidCharacter {
+                    character: '.',
+                    position: position - 1,
+                });
+            }
+            '@' => return Ok(position),
+            '.' if position == 0 || previous == Some('.') => {
+                return Err(EmailErrorKind::InvalidCharacter {
+                    character,
+                    position,
+                });
+            }
+            '.' => {}
+            c if is_atext(c) => {}
+            _ => {
+                return Err(EmailErrorKind::InvalidCharacter {
+                    character,
+                    position,
+                });
+            }
+        }
+
+        previous = Some(character);
+    }
+
+    Err(EmailErrorKind::MissingSeparator)
+}
+
+/// The WHATWG HTML grammar allows dots anywhere, including at the start and end.
+fn parse_html5_local_part(raw: &str) -> Result<usize, EmailErrorKind> {
+    for (position, character) in raw.char_indices() {
+        match character {
+            '@' if position == 0 => return Err(EmailErrorKind::LocalPartEmpty),
+            '@' => return Ok(position),
+            '.' => {}
+            c if c.is_ascii() && is_atext(c) => {}
+            _ => {
+                return Err(EmailErrorKind::InvalidCharacter {
+                    character,
+                    position,
+                });
+            }
+        }
+    }
+
+    Err(EmailErrorKind::MissingSeparator)
+}
+
+fn parse_quoted_local_part(raw: &str, grammar: Grammar) -> Result<usize, EmailErrorKind> {
+    // RFC 5322 allows folding whitespace within quoted strings,
+    // where RFC 5321 only allows spaces.
+    let is_quoted_wsp = |c| c == ' ' || (c == '\t' && grammar == Grammar::Rfc5322);
+
+    let mut chars = raw.char_indices().skip(1);
+
+    while let Some((position, character)) = chars.next() {
+        match character {
+            '"' => {
+                let after_quote = position + 1;
+                return match raw[after_quote..].chars().next() {
+                    Some('@') if position == 1 => Err(EmailErrorKind::LocalPartEmpty),
+                    Some('@') => Ok(after_quote),
+                    Some(character) => Err(EmailErrorKind::InvalidCharacter {
+                        character,
+                        position: after_quote,
+                    }),
+                    None => Err(EmailErrorKind::MissingSeparator),
+                };
+            }
+            '\\' => match chars.next() {
+                Some((_, escaped)) if is_vchar(escaped) || is_quoted_wsp(escaped) => {}
+                Some((position, character)) => {
+                    return Err(EmailErrorKind::InvalidCharacter {
+                        character,
+                        position,
+                    });
+                }
+                None => break,
+            },
+            c if is_qtext(c) || is_quoted_wsp(c) => {}
+            _ => {
+                return Err(EmailErrorKind::InvalidCharacter {
+                    character,
+                    position,
+                });
+            }
+        }
+    }
+
+    Err(EmailErrorKind::UnbalancedQuotes { position: 0 })
+}
+
+fn parse_domain(raw: &str, start: usize, policy: &ValidationPolicy) -> Result<(), EmailErrorKind> {
+    let domain = &raw[start..];
+    if domain.is_empty() {
+        return Err(EmailErrorKind::DomainEmpty);
+    }
+
+    if domain.starts_with('[') {
+        if policy.grammar == Grammar::Html5 {
+            return Err(EmailErrorKind::InvalidCharacter {
+                character: '[',
+                position: start,
+            });
+        }
+
+        if !policy.allow_domain_literals {
+            return Err(EmailErrorKind::DomainLiteralNotAllowed);
+        }
+
+        parse_domain_literal(raw, start)?;
+
+        let literal = &raw[start + 1..raw.len() - 1];
+        let domain = parse_literal_address(literal, start)?;
+        if domain == Domain::General
+            && policy.grammar == Grammar::Rfc5321
+            && !is_general_address_literal(literal)
+        {
+            return Err(EmailErrorKind::InvalidDomainLiteral { position: start });
+        }
+
+        if domain.is_private() && !policy.allow_private_domain_literals {
+            return Err(EmailErrorKind::PrivateDomainLiteral { position: start });
+        }
+    } else {
+        parse_domain_name(raw, start, policy)?;
+    }
+
+    if domain.len() > policy.max_domain_length {
+        return Err(EmailErrorKind::DomainTooLong {
+            length: domain.len(),
+            max: policy.max_domain_length,
+        });
+    }
+
+    Ok(())
+}
+
+fn parse_domain_literal(raw: &str, start: usize) -> Result<(), EmailErrorKind> {
+    let content_start = start + 1;
+    let last_index = raw.len() - 1;
+
+    for (offset, character) in raw[content_start..].char_indices() {
+        let position = content_start + offset;
+
+        match character {
+            ']' if position == last_index && position > content_start => return Ok(()),
+            c if is_dtext(c) => {}
+            _ => {
+                return Err(EmailErrorKind::InvalidCharacter {
+                    character,
+                    position,
+                });
+            }
+        }
+    }
+
+    Err(EmailErrorKind::UnbalancedBrackets { position: start })
+}
+
+/// Works out the kind of address within a domain literal, without the brackets.
+///
+/// Literals which look like an IPv4 address, or which start with `IPv6:`,
+/// must hold a valid address. Anything else is a general literal.
+pub(crate) fn parse_literal_address(literal: &str, start: usize) -> Result<Domain, EmailErrorKind> {
+    let ipv6 = literal
+        .get(..5)
+        .filter(|tag| tag.eq_ignore_ascii_case("IPv6:"))
+        .map(|_| &literal[5..]);
+    if let Some(ipv6) = ipv6 {
+        return ipv6
+            .parse::<Ipv6Addr>()
+            .map(Domain::Ipv6)
+            .map_err(|_| EmailErrorKind::InvalidIpv6Literal { position: start });
+    }
+
+    if literal.chars().all(|c| c.is_ascii_digit() || c == '.') {
+        return literal
+            .parse::<Ipv4Addr>()
+            .map(Domain::Ipv4)
+            .map_err(|_| EmailErrorKind::InvalidIpv4Literal { position: start });
+    }
+
+    Ok(Domain::General)
+}
+
+/// Checks a literal is a `General-address-literal` from RFC 5321, i.e. `tag:content`.
+fn is_general_address_literal(literal: &str) -> bool {
+    literal.split_once(':').is_some_and(|(tag, content)| {
+        is_standardized_tag(tag) && !content.is_empty() && content.chars().all(is_dcontent)
+    })
+}
+
+fn is_standardized_tag(tag: &str) -> bool {
+    tag.starts_with(|c: char| c.is_ascii_alphanumeric())
+        && tag.ends_with(|c: char| c.is_ascii_alphanumeric())
+        && tag.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+}
+
+fn is_dcontent(c: char) -> bool {
+    matches!(c, '\x21'..='\x5A' | '\x5E'..='\x7E')
+}
+
+fn parse_domain_name(
+    raw: &str,
+    start: usize,
+    policy: &ValidationPolicy,
+) -> Result<(), EmailErrorKind> {
+    let mut label_start = start;
+    let mut last_label = "";
+    let mut num_labels = 0;
+
+    for label in raw[start..].split('.') {
+        parse_label(label, label_start, policy.grammar)?;
+        label_start += label.len() + 1;
+        last_label = label;
+        num_labels += 1;
+    }
+
+    if policy.require_tld {
+        let is_numeric_tld = last_label.chars().all(|c| c.is_ascii_digit());
+        if num_labels < 2 || is_numeric_tld {
+            return Err(EmailErrorKind::MissingTld);
+        }
+    }
+
+    if num_labels < 2 && !policy.allow_single_label_domains {
+        return Err(EmailErrorKind::SingleLabelDomain);
+    }
+
+    #[cfg(feature = "disposable")]
+    if policy.reject_disposable_domains && is_built_in_disposable_domain(&raw[start..]) {
+        return Err(EmailErrorKind::DisposableDomain { position: start });
+    }
+
+    #[cfg(feature = "public-suffix")]
+    if policy.require_known_tld && !is_known_tld(last_label) {
+        return Err(EmailErrorKind::UnknownTld {
+            position: label_start - last_label.len() - 1,
+        });
+    }
+
+    Ok(())
+}
+
+fn parse_label(label: &str, position: usize, grammar: Grammar) -> Result<(), EmailErrorKind> {
+    for (offset, character) in label.char_indices() {
+        let is_allowed =
+            is_label_char(character) && (character.is_ascii() || grammar != Grammar::Html5);
+        if !is_allowed {
+            return Err(EmailErrorKind::InvalidCharacter {
+                character,
+                position: position + offset,
+            });
+        }
+    }
+
+    // Labels must start and end with a letter or digit.
+    // Like the WHATWG HTML spec, a leading digit is allowed.
+    let has_valid_edges =
+        label.starts_with(char::is_alphanumeric) && label.ends_with(char::is_alphanumeric);
+    if !has_valid_edges || label.len() > LABEL_MAX_LENGTH {
+        return Err(EmailErrorKind::InvalidLabel { position });
+    }
+
+    Ok(())
+}
+
+pub(crate) fn is_atext(c: char) -> bool {
+    c.is_ascii_alphanumeric() || "!#$%&'*+-/=?^_`{|}~".contains(c) || is_utf8_non_ascii(c)
+}
+
+fn is_qtext(c: char) -> bool {
+    matches!(c, '\x21' | '\x23'..='\x5B' | '\x5D'..='\x7E') || is_utf8_non_ascii(c)
+}
+
+fn is_dtext(c: char) -> bool {
+    matches!(c, '\x21'..='\x5A' | '\x5E'..='\x7E') || is_utf8_non_ascii(c)
+}
+
+fn is_label_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '-' || is_utf8_non_ascii(c)
+}
+
+fn is_vchar(c: char) -> bool {
+    matches!(c, '\x21'..='\x7E') || is_utf8_non_ascii(c)
+}
+
+/// As per RFC 6532, any non-ASCII character is allowed where ASCII text is.
+/// Control characters are still excluded.
+fn is_utf8_non_ascii(c: char) -> bool {
+    !c.is_ascii() && !c.is_control()
+}
+
+#[cfg(test)]
+mod test_parse_email {
+    use super::*;
+
+    fn parse_email(raw: &str) -> Result<usize, EmailErrorKind> {
+        super::parse_email(raw, &ValidationPolicy::new())
+    }
+
+    #[test]
+    fn it_should_return_the_index_of_the_at() {
+        assert_eq!(parse_email("john@example.com"), Ok(4));
+    }
+
+    #[test]
+    fn it_should_skip_the_at_in_quoted_local_parts() {
+        assert_eq!(parse_email(r#""a@b"@example.com"#), Ok(5));
+    }
+
+    #[test]
+    fn it_should_accept_escaped_quotes_in_quoted_local_parts() {
+        assert_eq!(parse_email(r#""a\"b"@example.com"#), Ok(6));
+    }
+
+    #[test]
+    fn it_should_accept_domain_literals() {
+        assert_eq!(parse_email("john@[192.168.0.1]"), Ok(4));
+    }
+
+    #[test]
+    fn it_should_accept_non_ascii() {
+        assert_eq!(parse_email("jöhn@bücher.de"), Ok(5));
+    }
+
+    #[test]
+    fn it_should_reject_a_missing_separator() {
+        assert_eq!(parse_email("foxes"), Err(EmailErrorKind::MissingSeparator));
+        assert_eq!(parse_email(""), Err(EmailErrorKind::MissingSeparator));
+    }
+
+    #[test]
+    fn it_should_reject_an_empty_local_part() {
+        assert_eq!(
+            parse_email("@example.com"),
+            Err(EmailErrorKind::LocalPartEmpty)
+        );
+        assert_eq!(
+            parse_email(r#"""@example.com"#),
+            Err(EmailErrorKind::LocalPartEmpty)
+        );
+    }
+
+    #[test]
+    fn it_should_reject_a_long_local_part() {
+        let raw = format!("{}@example.com", "a".repeat(65));
+
+        assert_eq!(
+            parse_email(&raw),
+            Err(EmailErrorKind::LocalPartTooLong {
+                length: 65,
+                max: 64
+            })
+        );
+    }
+
+    #[test]
+    fn it_should_reject_an_empty_domain() {
+        assert_eq!(parse_email("john@"), Err(EmailErrorKind::DomainEmpty));
+    }
+
+    #[test]
+    fn it_should_accept_a_domain_at_the_maximum_length() {
+        let domain = vec!["a".repeat(63); 4].join(".");
+        let raw = format!("john@{domain}");
+        let policy = ValidationPolicy::new().max_length(usize::MAX);
+
+        assert_eq!(super::parse_email(&raw, &policy), Ok(4));
+    }
+
+    #[test]
+    fn it_should_accept_an_address_at_the_maximum_length() {
+        let domain = [
+            "a".repeat(62),
+            "a".repeat(62),
+            "a".repeat(61),
+            "a".repeat(61),
+        ]
+        .join(".");
+        let raw = format!("john@{domain}");
+
+        assert_eq!(raw.len(), 254);
+        assert_eq!(parse_email(&raw), Ok(4));
+    }
+
+    #[test]
+    fn it_should_reject_a_long_address() {
+        let domain = vec!["a".repeat(63); 4].join(".");
+        let raw = format!("john@{domain}");
+
+        assert_eq!(
+            parse_email(&raw),
+            Err(EmailErrorKind::AddressTooLong {
+                length: 260,
+                max: 254
+            })
+        );
+    }
+
+    #[test]
+    fn it_should_count_lengths_in_bytes() {
+        let raw = format!("{}@example.com", "ö".repeat(33));
+
+        assert_eq!(
+            parse_email(&raw),
+            Err(EmailErrorKind::LocalPartTooLong {
+                length: 66,
+                max: 64
+            })
+        );
+    }
+
+    #[test]
+    fn it_should_reject_a_long_domain() {
+        let domain = vec!["a"; 129].join(".");
+        let raw = format!("john@{domain}");
+
+        assert_eq!(
+            parse_email(&raw),
+            Err(EmailErrorKind::DomainTooLong {
+                length: 257,
+                max: 255
+            })
+        );
+    }
+
+    #[test]
+    fn it_should_reject_a_long_label() {
+        let raw = format!("john@{}.com", "a".repeat(64));
+
+        assert_eq!(
+            parse_email(&raw),
+            Err(EmailErrorKind::InvalidLabel { position: 5 })
+        );
+    }
+
+    #[test]
+    fn it_should_report_the_position_of_invalid_characters() {
+        assert_eq!(
+            parse_email("john doe@example.com"),
+            Err(EmailErrorKind::InvalidCharacter {
+                character: ' ',
+                position: 4
+            })
+        );
+        assert_eq!(
+            parse_email("john@exa!mple.com"),
+            Err(EmailErrorKind::InvalidCharacter {
+                character: '!',
+                position: 8
+            })
+        );
+        assert_eq!(
+            parse_email("john@example@com"),
+            Err(EmailErrorKind::InvalidCharacter {
+                character: '@',
+                position: 12
+            })
+        );
+    }
+
+    #[test]
+    fn it_should_report_misplaced_dots_in_local_parts() {
+        assert_eq!(
+            parse_email(".john@example.com"),
+            Err(EmailErrorKind::InvalidCharacter {
+                character: '.',
+                position: 0
+            })
+        );
+        assert_eq!(
+            parse_email("jo..hn@example.com"),
+            Err(EmailErrorKind::InvalidCharacter {
+                character: '.',
+                position: 3
+            })
+        );
+        assert_eq!(
+            parse_email("john.@example.com"),
+            Err(EmailErrorKind::InvalidCharacter {
+                character: '.',
+                position: 4
+            })
+        );
+    }
+
+    #[test]
+    fn it_should_report_invalid_labels() {
+        assert_eq!(
+            parse_email("john@example..com"),
+            Err(EmailErrorKind::InvalidLabel { position: 13 })
+        );
+        assert_eq!(
+            parse_email("john@-example.com"),
+            Err(EmailErrorKind::InvalidLabel { position: 5 })
+        );
+        assert_eq!(
+            parse_email("john@example.com."),
+            Err(EmailErrorKind::InvalidLabel { position: 17 })
+        );
+    }
+
+    #[test]
+    fn it_should_report_unbalanced_quotes() {
+        assert_eq!(
+            parse_email(r#""john@example.com"#),
+            Err(EmailErrorKind::UnbalancedQuotes { position: 0 })
+        );
+    }
+
+    #[test]
+    fn it_should_report_invalid_ip_literals() {
+        assert_eq!(
+            parse_email("john@[300.168.0.1]"),
+            Err(EmailErrorKind::InvalidIpv4Literal { position: 5 })
+        );
+        assert_eq!(
+            parse_email("john@[1.2.3]"),
+            Err(EmailErrorKind::InvalidIpv4Literal { position: 5 })
+        );
+        assert_eq!(
+            parse_email("john@[IPv6:2001:db8::g]"),
+            Err(EmailErrorKind::InvalidIpv6Literal { position: 5 })
+        );
+    }
+
+    #[test]
+    fn it_should_report_unbalanced_brackets() {
+        assert_eq!(
+            parse_email("john@[192.168.0.1"),
+            Err(EmailErrorKind::UnbalancedBrackets { position: 5 })
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_grammar_conformance {
+    use super::*;
+
+    /// Each address, with if it is valid under RFC 5322, RFC 5321, and HTML5.
+    const CASES: &[(&str, bool, bool, bool)] = &[
+        ("john@example.com", true, true, true),
+        ("john.doe+tag@example.com", true, true, true),
+        ("a{b}|c~`'@example.com", true, true, true),
+        ("john@123.com", true, true, true),
+        ("john@localhost", true, true, true),
+        ("john@xn--bcher-kva.de", true, true, true),
+        (".john@example.com", false, false, true),
+        ("john.@example.com", false, false, true),
+        ("john..doe@example.com", false, false, true),
+        (r#""john doe"@example.com"#, true, true, false),
+        (r#""john\ doe"@example.com"#, true, true, false),
+        ("\"john\tdoe\"@example.com", true, false, false),
+        ("\"john\\\tdoe\"@example.com", true, false, false),
+        ("john@[192.168.0.1]", true, true, false),
+        ("john@[300.168.0.1]", false, false, false),
+        ("john@[IPv6:2001:db8::1]", true, true, false),
+        ("john@[ipv6:2001:db8::1]", true, true, false),
+        ("john@[IPv6:nope]", false, false, false),
+        ("john@[x-tag:content]", true, true, false),
+        ("john@[-tag:content]", true, false, false),
+        ("john@[anything]", true, false, false),
+        ("jöhn@example.com", true, true, false),
+        ("john@bücher.de", true, true, false),
+        ("john doe@example.com", false, false, false),
+        ("john@-example.com", false, false, false),
+        ("john@example-.com", false, false, false),
+        ("john@example..com", false, false, false),
+        ("john@example.com.", false, false, false),
+        ("john@exa_mple.com", false, false, false),
+        ("@example.com", false, false, false),
+        ("john@", false, false, false),
+        ("john", false, false, false),
+    ];
+
+    fn is_valid(raw: &str, grammar: Grammar) -> bool {
+        parse_email(raw, &ValidationPolicy::new().grammar(grammar)).is_ok()
+    }
+
+    #[test]
+    fn it_should_match_the_rfc_5322_grammar() {
+        for &(raw, expected, _, _) in CASES {
+            assert_eq!(is_valid(raw, Grammar::Rfc5322), expected, "{raw:?}");
+        }
+    }
+
+    #[test]
+    fn it_should_match_the_rfc_5321_grammar() {
+        for &(raw, _, expected, _) in CASES {
+            assert_eq!(is_valid(raw, Grammar::Rfc5321), expected, "{raw:?}");
+        }
+    }
+
+    #[test]
+    fn it_should_match_the_html5_grammar() {
+        for &(raw, _, _, expected) in CASES {
+            assert_eq!(is_valid(raw, Grammar::Html5), expected, "{raw:?}");
+        }
+    }
+
+    #[test]
+    fn it_should_report_invalid_address_literals() {
+        let policy = ValidationPolicy::new().grammar(Grammar::Rfc5321);
+
+        assert_eq!(
+            parse_email("john@[anything]", &policy),
+            Err(EmailErrorKind::InvalidDomainLiteral { position: 5 })
+        );
+    }
+
+    #[test]
+    fn it_should_report_non_ascii_in_html5() {
+        let policy = ValidationPolicy::new().grammar(Grammar::Html5);
+
+        assert_eq!(
+            parse_email("john@bücher.de", &policy),
+            Err(EmailErrorKind::InvalidCharacter {
+                character: 'ü',
+                position: 6
+            })
+        );
+    }
+}
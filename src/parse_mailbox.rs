@@ -0,0 +1,438 @@
+use ::alloc::string::String;
+use ::alloc::string::ToString;
+use ::alloc::vec::Vec;
+
+use crate::encoded_word::decode_encoded_word;
+use crate::parse_email::is_atext;
+use crate::Email;
+use crate::EmailErrorKind;
+use crate::ValidationPolicy;
+
+enum Word<'a> {
+    Atom(&'a str),
+    Quoted(String),
+}
+
+/// Parses a mailbox, as defined by RFC 5322.
+///
+/// This is either an address on its own, i.e. `jane@example.com`,
+/// or a display name followed by an address in angle brackets,
+/// i.e. `"Jane Doe" <jane@example.com>`. Comments are allowed around both,
+/// and encoded-words in the display name are decoded.
+///
+/// Returns the display name, if there is one, and the address.
+pub(crate) fn parse_mailbox(
+    raw: &str,
+    policy: &ValidationPolicy,
+) -> Result<(Option<String>, Email), EmailErrorKind> {
+    match find_angle_start(raw) {
+        Some(angle_start) => {
+            let display_name = parse_display_name(raw, angle_start)?;
+            let email = parse_angle_addr(raw, angle_start, policy)?;
+
+            Ok((display_name, email))
+        }
+        None => {
+            let start = skip_cfws(raw, 0)?;
+            let end = find_addr_spec_end(raw, start);
+            let email = parse_addr_spec(raw, start, end, policy)?;
+            expect_end(raw, end)?;
+
+            Ok((None, email))
+        }
+    }
+}
+
+pub(crate) fn parse_display_name(raw: &str, end: usize) -> Result<Option<String>, EmailErrorKind> {
+    let mut words = Vec::new();
+    let mut position = 0;
+
+    loop {
+        position = skip_cfws(&raw[..end], position)?;
+        let Some(character) = raw[position..end].chars().next() else {
+            break;
+        };
+
+        if character == '"' {
+            let (text, next_position) = read_quoted_string(&raw[..end], position)?;
+            words.push(Word::Quoted(text));
+            position = next_position;
+        } else if is_phrase_char(character) {
+            let length = raw[position..end]
+                .find(|c| !is_phrase_char(c))
+                .unwrap_or(end - position);
+            words.push(Word::Atom(&raw[position..position + length]));
+            position += length;
+        } else {
+            return Err(EmailErrorKind::InvalidCharacter {
+                character,
+                position,
+            });
+        }
+    }
+
+    let mut display_name = String::new();
+    let mut is_previous_encoded = false;
+
+    for (i, word) in words.into_iter().enumerate() {
+        let (text, is_encoded) = match word {
+            Word::Atom(atom) => match decode_encoded_word(atom) {
+                Some(decoded) => (decoded, true),
+                None => (atom.to_string(), false),
+            },
+            Word::Quoted(text) => (text, false),
+        };
+
+        // Whitespace between two encoded-words is not part of the text.
+        if i > 0 && !(is_encoded && is_previous_encoded) {
+            display_name.push(' ');
+        }
+
+        display_name.push_str(&text);
+        is_previous_encoded = is_encoded;
+    }
+
+    if display_name.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(display_name))
+    }
+}
+
+fn parse_angle_addr(
+    raw: &str,
+    angle_start: usize,
+    policy: &ValidationPolicy,
+) -> Result<Email, EmailErrorKind> {
+    let angle_end =
+        find_angle_end(raw, angle_start).ok_or(EmailErrorKind::UnbalancedAngleBrackets {
+            position: angle_start,
+        })?;
+
+    let inner = &raw[angle_start + 1..angle_end];
+    let start = angle_start + 1 + (inner.len() - inner.trim_start().len());
+    let end = angle_end - (inner.len() - inner.trim_end().len());
+    let email = parse_addr_spec(raw, start, end.max(start), policy)?;
+    expect_end(raw, angle_end + 1)?;
+
+    Ok(email)
+}
+
+fn parse_addr_spec(
+    raw: &str,
+    start: usize,
+    end: usize,
+    policy: &ValidationPolicy,
+) -> Result<Email, EmailErrorKind> {
+    Email::from_str_with(&raw[start..end], policy).map_err(|err| err.kind().offset_by(start))
+}
+
+/// Checks there is nothing but whitespace and comments from the position to the end.
+fn expect_end(raw: &str, position: usize) -> Result<(), EmailErrorKind> {
+    let position = skip_cfws(raw, position)?;
+
+    match raw[position..].chars().next() {
+        Some(character) => Err(EmailErrorKind::InvalidCharacter {
+            character,
+            position,
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Returns the position after any whitespace and comments.
+pub(crate) fn skip_cfws(raw: &str, mut position: usize) -> Result<usize, EmailErrorKind> {
+    while let Some(character) = raw[position..].chars().next() {
+        match character {
+            ' ' | '\t' | '\r' | '\n' => position += 1,
+            '(' => position = skip_comment(raw, position)?,
+            _ => break,
+        }
+    }
+
+    Ok(position)
+}
+
+/// Returns the position after the comment starting at the position given.
+/// Comments can be nested.
+fn skip_comment(raw: &str, start: usize) -> Result<usize, EmailErrorKind> {
+    let mut depth = 0;
+    let mut chars = raw[start..].char_indices();
+
+    while let Some((offset, character)) = chars.next() {
+        match character {
+            '\\' => {
+                chars.next();
+            }
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Ok(start + offset + 1);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Err(EmailErrorKind::UnbalancedParentheses { position: start })
+}
+
+/// Reads the quoted string starting at the position given,
+/// returning the unescaped text and the position after it.
+pub(crate) fn read_quoted_string(
+    raw: &str,
+    start: usize,
+) -> Result<(String, usize), EmailErrorKind> {
+    let mut text = String::new();
+    let mut chars = raw[start + 1..].char_indices();
+
+    while let Some((offset, character)) = chars.next() {
+        match character {
+            '\\' => {
+                if let Some((_, escaped)) = chars.next() {
+                    text.push(escaped);
+                }
+            }
+            '"' => return Ok((text, start + 1 + offset + 1)),
+            _ => text.push(character),
+        }
+    }
+
+    Err(EmailErrorKind::UnbalancedQuotes { position: start })
+}
+
+/// Finds the `<` starting the address, ignoring any in quotes or comments.
+fn find_angle_start(raw: &str) -> Option<usize> {
+    let mut is_quoted = false;
+    let mut comment_depth = 0;
+    let mut chars = raw.char_indices();
+
+    while let Some((position, character)) = chars.next() {
+        match character {
+            '\\' if is_quoted || comment_depth > 0 => {
+                chars.next();
+            }
+            '"' if comment_depth == 0 => is_quoted = !is_quoted,
+            '(' if !is_quoted => comment_depth += 1,
+            ')' if !is_quoted && comment_depth > 0 => comment_depth -= 1,
+            '<' if !is_quoted && comment_depth == 0 => return Some(position),
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Finds the `>` ending the address, ignoring any in a quoted local part.
+fn find_angle_end(raw: &str, angle_start: usize) -> Option<usize> {
+    let mut is_quoted = false;
+    let mut chars = raw[angle_start..].char_indices();
+
+    while let Some((offset, character)) = chars.next() {
+        match character {
+            '\\' if is_quoted => {
+                chars.next();
+            }
+            '"' => is_quoted = !is_quoted,
+            '>' if !is_quoted => return Some(angle_start + offset),
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Finds the end of an address without angle brackets,
+/// which is the first whitespace or comment outside of quotes or a domain literal.
+fn find_addr_spec_end(raw: &str, start: usize) -> usize {
+    let mut is_quoted = false;
+    let mut is_literal = false;
+    let mut chars = raw[start..].char_indices();
+
+    while let Some((offset, character)) = chars.next() {
+        match character {
+            '\\' if is_quoted => {
+                chars.next();
+            }
+            '"' => is_quoted = !is_quoted,
+            '[' if !is_quoted => is_literal = true,
+            ']' if !is_quoted => is_literal = false,
+            ' ' | '\t' | '\r' | '\n' | '(' if !is_quoted && !is_literal => {
+                return start + offset;
+            }
+            _ => {}
+        }
+    }
+
+    raw.len()
+}
+
+/// Characters allowed unquoted in a display name.
+/// Dots are allowed for names like `John Q. Public`, as per the obsolete syntax of RFC 5322.
+fn is_phrase_char(c: char) -> bool {
+    is_atext(c) || c == '.'
+}
+
+#[cfg(test)]
+mod test_parse_mailbox {
+    use super::*;
+
+    fn parse_mailbox(raw: &str) -> Result<(Option<String>, String), EmailErrorKind> {
+        super::parse_mailbox(raw, &ValidationPolicy::new())
+            .map(|(display_name, email)| (display_name, email.to_string()))
+    }
+
+    fn named(display_name: &str, email: &str) -> Result<(Option<String>, String), EmailErrorKind> {
+        Ok((Some(display_name.to_string()), email.to_string()))
+    }
+
+    #[test]
+    fn it_should_parse_bare_addresses() {
+        assert_eq!(
+            parse_mailbox("jane@example.com"),
+            Ok((None, "jane@example.com".to_string()))
+        );
+    }
+
+    #[test]
+    fn it_should_parse_bare_addresses_with_quoted_local_parts() {
+        assert_eq!(
+            parse_mailbox(r#""jane <doe>"@example.com"#),
+            Ok((None, r#""jane <doe>"@example.com"#.to_string()))
+        );
+    }
+
+    #[test]
+    fn it_should_parse_angle_addresses_without_a_name() {
+        assert_eq!(
+            parse_mailbox("<jane@example.com>"),
+            Ok((None, "jane@example.com".to_string()))
+        );
+    }
+
+    #[test]
+    fn it_should_parse_atom_names() {
+        assert_eq!(
+            parse_mailbox("Jane Doe <jane@example.com>"),
+            named("Jane Doe", "jane@example.com")
+        );
+    }
+
+    #[test]
+    fn it_should_parse_quoted_names() {
+        assert_eq!(
+            parse_mailbox(r#""Doe, Jane \"JD\"" <jane@example.com>"#),
+            named(r#"Doe, Jane "JD""#, "jane@example.com")
+        );
+    }
+
+    #[test]
+    fn it_should_parse_names_with_dots() {
+        assert_eq!(
+            parse_mailbox("John Q. Public <john@example.com>"),
+            named("John Q. Public", "john@example.com")
+        );
+    }
+
+    #[test]
+    fn it_should_collapse_whitespace_between_words() {
+        assert_eq!(
+            parse_mailbox("  Jane \t Doe   <jane@example.com>  "),
+            named("Jane Doe", "jane@example.com")
+        );
+    }
+
+    #[test]
+    fn it_should_decode_encoded_words() {
+        assert_eq!(
+            parse_mailbox("=?UTF-8?B?SsO2aG4=?= Doe <john@example.com>"),
+            named("Jöhn Doe", "john@example.com")
+        );
+    }
+
+    #[test]
+    fn it_should_join_adjacent_encoded_words() {
+        assert_eq!(
+            parse_mailbox("=?UTF-8?Q?J=C3=B6?= =?UTF-8?Q?hn?= <john@example.com>"),
+            named("Jöhn", "john@example.com")
+        );
+    }
+
+    #[test]
+    fn it_should_skip_comments() {
+        assert_eq!(
+            parse_mailbox("Jane (the (real) one) Doe <jane@example.com> (work)"),
+            named("Jane Doe", "jane@example.com")
+        );
+        assert_eq!(
+            parse_mailbox("(work) jane@example.com (Jane)"),
+            Ok((None, "jane@example.com".to_string()))
+        );
+    }
+
+    #[test]
+    fn it_should_allow_whitespace_inside_angle_brackets() {
+        assert_eq!(
+            parse_mailbox("Jane < jane@example.com >"),
+            named("Jane", "jane@example.com")
+        );
+    }
+
+    #[test]
+    fn it_should_report_invalid_addresses_relative_to_the_mailbox() {
+        assert_eq!(
+            parse_mailbox("Jane <jane@exa!mple.com>"),
+            Err(EmailErrorKind::InvalidCharacter {
+                character: '!',
+                position: 14
+            })
+        );
+    }
+
+    #[test]
+    fn it_should_report_unclosed_angle_brackets() {
+        assert_eq!(
+            parse_mailbox("Jane <jane@example.com"),
+            Err(EmailErrorKind::UnbalancedAngleBrackets { position: 5 })
+        );
+    }
+
+    #[test]
+    fn it_should_report_unclosed_quotes() {
+        assert_eq!(
+            parse_mailbox(r#""Jane <jane@example.com>"#),
+            Err(EmailErrorKind::UnbalancedQuotes { position: 0 })
+        );
+    }
+
+    #[test]
+    fn it_should_report_unclosed_comments() {
+        assert_eq!(
+            parse_mailbox("Jane <jane@example.com> (work"),
+            Err(EmailErrorKind::UnbalancedParentheses { position: 24 })
+        );
+    }
+
+    #[test]
+    fn it_should_report_text_after_the_address() {
+        assert_eq!(
+            parse_mailbox("Jane <jane@example.com> Doe"),
+            Err(EmailErrorKind::InvalidCharacter {
+                character: 'D',
+                position: 24
+            })
+        );
+    }
+
+    #[test]
+    fn it_should_report_invalid_characters_in_names() {
+        assert_eq!(
+            parse_mailbox("Jane, Doe <jane@example.com>"),
+            Err(EmailErrorKind::InvalidCharacter {
+                character: ',',
+                position: 4
+            })
+        );
+    }
+}
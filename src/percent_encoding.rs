@@ -0,0 +1,87 @@
+use ::alloc::format;
+use ::alloc::string::String;
+use ::alloc::vec::Vec;
+
+use crate::encoded_word::hex_value;
+
+/// Percent encodes every byte of the text, for which `is_allowed` returns false.
+pub(crate) fn percent_encode(text: &str, is_allowed: fn(u8) -> bool) -> String {
+    let mut encoded = String::with_capacity(text.len());
+
+    for &byte in text.as_bytes() {
+        if is_allowed(byte) {
+            encoded.push(char::from(byte));
+        } else {
+            encoded.push_str(&format!("%{byte:02X}"));
+        }
+    }
+
+    encoded
+}
+
+/// Decodes percent encoded bytes within the text.
+///
+/// Malformed escapes are left as they are,
+/// and invalid UTF-8 is replaced with `U+FFFD`.
+pub(crate) fn percent_decode(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let escaped = bytes
+            .get(i + 1..i + 3)
+            .filter(|_| bytes[i] == b'%')
+            .and_then(|hex| Some(hex_value(hex[0])? * 16 + hex_value(hex[1])?));
+
+        match escaped {
+            Some(byte) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            None => {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// The characters which are never percent encoded in a URI.
+pub(crate) fn is_unreserved(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~')
+}
+
+#[cfg(test)]
+mod test_percent_encoding {
+    use super::*;
+
+    #[test]
+    fn it_should_encode_bytes_not_allowed() {
+        assert_eq!(percent_encode("a b&c", is_unreserved), "a%20b%26c");
+    }
+
+    #[test]
+    fn it_should_encode_utf8_bytes() {
+        assert_eq!(percent_encode("ö", is_unreserved), "%C3%B6");
+    }
+
+    #[test]
+    fn it_should_decode_escapes() {
+        assert_eq!(percent_decode("a%20b%26c%C3%B6"), "a b&cö");
+    }
+
+    #[test]
+    fn it_should_leave_malformed_escapes() {
+        assert_eq!(percent_decode("100%"), "100%");
+        assert_eq!(percent_decode("%zz%2"), "%zz%2");
+    }
+
+    #[test]
+    fn it_should_only_decode_two_hex_digits() {
+        assert_eq!(percent_decode("a%+Ab"), "a%+Ab");
+        assert_eq!(percent_decode("a%-1b"), "a%-1b");
+    }
+}
@@ -0,0 +1,226 @@
+use ::alloc::string::String;
+use ::alloc::string::ToString;
+use ::alloc::vec;
+use ::alloc::vec::Vec;
+
+use crate::split_subaddress::split_subaddress;
+
+/// Describes how a mail provider treats the local part of its addresses.
+///
+/// This is used by `ProviderRules` to build canonical forms of addresses,
+/// where different addresses delivering to the same inbox become the same.
+///
+/// ```rust
+/// use ::serde_email::ProviderRule;
+///
+/// let rule = ProviderRule::new("example.com")
+///     .with_domain("example.net")
+///     .with_canonical_domain("example.com")
+///     .with_subaddress_separator('+')
+///     .ignore_dots(true);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProviderRule {
+    domains: Vec<String>,
+    canonical_domain: Option<String>,
+    subaddress_separator: Option<char>,
+    ignore_dots: bool,
+    ignore_case: bool,
+}
+
+impl ProviderRule {
+    /// Creates a rule for the given domain.
+    ///
+    /// By default local parts are case insensitive, dots are kept,
+    /// and there is no subaddress separator.
+    pub fn new<S>(domain: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            domains: vec![domain.into().to_lowercase()],
+            canonical_domain: None,
+            subaddress_separator: None,
+            ignore_dots: false,
+            ignore_case: true,
+        }
+    }
+
+    /// Adds another domain which this rule also applies to.
+    pub fn with_domain<S>(mut self, domain: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.domains.push(domain.into().to_lowercase());
+        self
+    }
+
+    /// Sets the domain which all of the domains of this rule are replaced with.
+    ///
+    /// This is for providers with aliases, like `googlemail.com` and `gmail.com`.
+    /// Without this, the domain is kept as it is.
+    ///
+    /// The domain is validated when it is used, by `ProviderRules::canonicalize`.
+    pub fn with_canonical_domain<S>(self, domain: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            canonical_domain: Some(domain.into().to_lowercase()),
+            ..self
+        }
+    }
+
+    /// Sets the character separating the mailbox from a subaddress,
+    /// like the `+` in `john+news@gmail.com`.
+    /// The separator, and everything after it, is removed.
+    pub fn with_subaddress_separator(self, separator: char) -> Self {
+        Self {
+            subaddress_separator: Some(separator),
+            ..self
+        }
+    }
+
+    /// Sets if dots in the local part are ignored by the provider,
+    /// in which case they are removed.
+    pub fn ignore_dots(self, ignore: bool) -> Self {
+        Self {
+            ignore_dots: ignore,
+            ..self
+        }
+    }
+
+    /// Sets if the local part is case insensitive with the provider,
+    /// in which case it is lowercased.
+    pub fn ignore_case(self, ignore: bool) -> Self {
+        Self {
+            ignore_case: ignore,
+            ..self
+        }
+    }
+
+    pub fn domains(&self) -> &[String] {
+        &self.domains
+    }
+
+    /// Returns if this rule applies to the domain given.
+    /// The domain should already be lowercased.
+    pub(crate) fn matches(&self, domain: &str) -> bool {
+        self.domains.iter().any(|rule_domain| rule_domain == domain)
+    }
+
+    /// Returns the local part and domain after applying this rule.
+    pub(crate) fn apply(&self, local_part: &str, domain: &str) -> (String, String) {
+        let domain = self
+            .canonical_domain
+            .clone()
+            .unwrap_or_else(|| domain.to_string());
+
+        // Quoted local parts are left alone, as providers with rules don't issue them.
+        if local_part.starts_with('"') {
+            return (local_part.to_string(), domain);
+        }
+
+        let mailbox = self
+            .subaddress_separator
+            .and_then(|separator| split_subaddress(local_part, separator))
+            .map(|(mailbox, _)| mailbox)
+            .unwrap_or(local_part);
+
+        let mut canonical_local_part = if self.ignore_dots {
+            let without_dots = mailbox.replace('.', "");
+            if without_dots.is_empty() {
+                mailbox.to_string()
+            } else {
+                without_dots
+            }
+        } else {
+            mailbox.to_string()
+        };
+
+        if self.ignore_case {
+            canonical_local_part = canonical_local_part.to_lowercase();
+        }
+
+        (canonical_local_part, domain)
+    }
+}
+
+#[cfg(test)]
+mod test_apply {
+    use super::*;
+
+    #[test]
+    fn it_should_remove_the_subaddress() {
+        let rule = ProviderRule::new("example.com").with_subaddress_separator('+');
+
+        assert_eq!(
+            rule.apply("john+news", "example.com"),
+            ("john".to_string(), "example.com".to_string())
+        );
+    }
+
+    #[test]
+    fn it_should_not_leave_a_trailing_dot_when_removing_the_subaddress() {
+        let rule = ProviderRule::new("example.com").with_subaddress_separator('+');
+
+        assert_eq!(
+            rule.apply("john.+news", "example.com"),
+            ("john".to_string(), "example.com".to_string())
+        );
+    }
+
+    #[test]
+    fn it_should_keep_local_parts_which_are_only_a_subaddress() {
+        let rule = ProviderRule::new("example.com").with_subaddress_separator('+');
+
+        assert_eq!(
+            rule.apply("+news", "example.com"),
+            ("+news".to_string(), "example.com".to_string())
+        );
+    }
+
+    #[test]
+    fn it_should_remove_dots_when_ignored() {
+        let rule = ProviderRule::new("example.com").ignore_dots(true);
+
+        assert_eq!(
+            rule.apply("j.o.h.n", "example.com"),
+            ("john".to_string(), "example.com".to_string())
+        );
+    }
+
+    #[test]
+    fn it_should_keep_case_when_not_ignored() {
+        let rule = ProviderRule::new("example.com").ignore_case(false);
+
+        assert_eq!(
+            rule.apply("John", "example.com"),
+            ("John".to_string(), "example.com".to_string())
+        );
+    }
+
+    #[test]
+    fn it_should_replace_the_domain_with_the_canonical_domain() {
+        let rule = ProviderRule::new("example.com")
+            .with_domain("example.net")
+            .with_canonical_domain("example.com");
+
+        assert_eq!(
+            rule.apply("john", "example.net"),
+            ("john".to_string(), "example.com".to_string())
+        );
+    }
+
+    #[test]
+    fn it_should_leave_quoted_local_parts_alone() {
+        let rule = ProviderRule::new("example.com")
+            .with_subaddress_separator('+')
+            .ignore_dots(true);
+
+        assert_eq!(
+            rule.apply(r#""J.o+hn""#, "example.com"),
+            (r#""J.o+hn""#.to_string(), "example.com".to_string())
+        );
+    }
+}
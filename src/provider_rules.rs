@@ -0,0 +1,230 @@
+use ::alloc::format;
+use ::alloc::vec::Vec;
+
+use crate::Email;
+use crate::EmailError;
+use crate::ProviderRule;
+
+/// A table of `ProviderRule`s, used for building canonical forms of addresses.
+///
+/// `ProviderRules::new` includes built in rules for well known providers.
+/// Further rules can be added, and are checked before the built in ones.
+///
+/// ```rust
+/// use ::serde_email::Email;
+/// use ::serde_email::ProviderRule;
+/// use ::serde_email::ProviderRules;
+///
+/// let rules = ProviderRules::new()
+///     .with_rule(ProviderRule::new("example.com").with_subaddress_separator('-'));
+///
+/// let email = Email::from_str("john-news@example.com").unwrap();
+/// assert_eq!(rules.canonicalize(&email).unwrap(), "john@example.com");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProviderRules {
+    rules: Vec<ProviderRule>,
+}
+
+impl ProviderRules {
+    /// Creates a table with rules for well known providers.
+    ///
+    ///  * Gmail, which ignores dots, uses `+` for subaddresses,
+    ///    and treats `googlemail.com` as an alias of `gmail.com`.
+    ///  * Outlook (including Hotmail, Live, and MSN), which uses `+` for subaddresses.
+    ///  * Fastmail, which uses `+` for subaddresses.
+    ///  * ProtonMail, which uses `+` for subaddresses,
+    ///    and treats `protonmail.ch`, `proton.me`, and `pm.me` as aliases of `protonmail.com`.
+    pub fn new() -> Self {
+        Self::empty()
+            .with_rule(
+                ProviderRule::new("gmail.com")
+                    .with_domain("googlemail.com")
+                    .with_canonical_domain("gmail.com")
+                    .with_subaddress_separator('+')
+                    .ignore_dots(true),
+            )
+            .with_rule(
+                ProviderRule::new("outlook.com")
+                    .with_domain("hotmail.com")
+                    .with_domain("live.com")
+                    .with_domain("msn.com")
+                    .with_subaddress_separator('+'),
+            )
+            .with_rule(
+                ProviderRule::new("fastmail.com")
+                    .with_domain("fastmail.fm")
+                    .with_subaddress_separator('+'),
+            )
+            .with_rule(
+                ProviderRule::new("protonmail.com")
+                    .with_domain("protonmail.ch")
+                    .with_domain("proton.me")
+                    .with_domain("pm.me")
+                    .with_canonical_domain("protonmail.com")
+                    .with_subaddress_separator('+'),
+            )
+    }
+
+    /// Creates a table with no rules.
+    pub fn empty() -> Self {
+        Self { rules: Vec::new() }
+    }
+
+    /// Adds a rule, which takes priority over the rules already in the table.
+    pub fn with_rule(mut self, rule: ProviderRule) -> Self {
+        self.rules.insert(0, rule);
+        self
+    }
+
+    /// Returns the rule used for the domain given, if there is one.
+    pub fn find<S>(&self, domain: S) -> Option<&ProviderRule>
+    where
+        S: AsRef<str>,
+    {
+        let domain = domain.as_ref().to_lowercase();
+        self.rules.iter().find(|rule| rule.matches(&domain))
+    }
+
+    /// Returns the canonical form of the email given, for the provider of its domain.
+    ///
+    /// The domain is always lowercased. If there is a rule for the domain,
+    /// then that rule is also applied to the local part.
+    /// The Email given is left unchanged.
+    ///
+    /// The result is validated again, and an error is returned if it is invalid.
+    /// Such as when a rule has an invalid canonical domain,
+    /// or lowercasing makes the address too long.
+    pub fn canonicalize(&self, email: &Email) -> Result<Email, EmailError> {
+        let domain = email.domain().to_lowercase();
+
+        let raw_email = match self.rules.iter().find(|rule| rule.matches(&domain)) {
+            Some(rule) => {
+                let (local_part, domain) = rule.apply(email.local_part(), &domain);
+                format!("{local_part}@{domain}")
+            }
+            None => format!("{}@{domain}", email.local_part()),
+        };
+
+        Email::from_string(raw_email)
+    }
+}
+
+/// Same as `ProviderRules::new`.
+impl Default for ProviderRules {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test_canonicalize {
+    use super::*;
+    use crate::EmailErrorKind;
+
+    fn canonicalize(raw: &str) -> Email {
+        ProviderRules::new()
+            .canonicalize(&Email::from_str(raw).unwrap())
+            .unwrap()
+    }
+
+    #[test]
+    fn it_should_remove_gmail_dots_and_subaddresses() {
+        assert_eq!(canonicalize("j.o.h.n+1@gmail.com"), "john@gmail.com");
+    }
+
+    #[test]
+    fn it_should_replace_googlemail_with_gmail() {
+        assert_eq!(canonicalize("john@googlemail.com"), "john@gmail.com");
+    }
+
+    #[test]
+    fn it_should_lowercase_gmail_addresses() {
+        assert_eq!(canonicalize("John.Smith@GMail.com"), "johnsmith@gmail.com");
+    }
+
+    #[test]
+    fn it_should_keep_dots_for_outlook() {
+        assert_eq!(
+            canonicalize("john.smith+shop@hotmail.com"),
+            "john.smith@hotmail.com"
+        );
+    }
+
+    #[test]
+    fn it_should_remove_fastmail_subaddresses() {
+        assert_eq!(canonicalize("john+work@fastmail.com"), "john@fastmail.com");
+    }
+
+    #[test]
+    fn it_should_replace_proton_aliases() {
+        assert_eq!(canonicalize("john+x@pm.me"), "john@protonmail.com");
+    }
+
+    #[test]
+    fn it_should_only_lowercase_the_domain_for_unknown_providers() {
+        assert_eq!(
+            canonicalize("J.ohn+news@Example.com"),
+            "J.ohn+news@example.com"
+        );
+    }
+
+    #[test]
+    fn it_should_prefer_added_rules() {
+        let rules =
+            ProviderRules::new().with_rule(ProviderRule::new("gmail.com").ignore_case(false));
+        let email = Email::from_str("J.ohn+x@gmail.com").unwrap();
+
+        assert_eq!(rules.canonicalize(&email).unwrap(), "J.ohn+x@gmail.com");
+    }
+
+    #[test]
+    fn it_should_reject_invalid_canonical_domains() {
+        let rules = ProviderRules::empty()
+            .with_rule(ProviderRule::new("example.com").with_canonical_domain("no spaces allowed"));
+        let email = Email::from_str("john@example.com").unwrap();
+        let err = rules.canonicalize(&email).unwrap_err();
+
+        assert_eq!(
+            err.kind(),
+            &EmailErrorKind::InvalidCharacter {
+                character: ' ',
+                position: 7,
+            }
+        );
+    }
+
+    #[test]
+    fn it_should_reject_local_parts_made_too_long_by_lowercasing() {
+        let rules = ProviderRules::empty().with_rule(ProviderRule::new("example.com"));
+        let email = Email::from_string(format!("{}@example.com", "Ⱥ".repeat(32))).unwrap();
+        let err = rules.canonicalize(&email).unwrap_err();
+
+        assert_eq!(
+            err.kind(),
+            &EmailErrorKind::LocalPartTooLong {
+                length: 96,
+                max: 64,
+            }
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_find {
+    use super::*;
+
+    #[test]
+    fn it_should_find_rules_ignoring_case() {
+        let rules = ProviderRules::new();
+
+        assert!(rules.find("GoogleMail.com").is_some());
+    }
+
+    #[test]
+    fn it_should_not_find_unknown_domains() {
+        let rules = ProviderRules::new();
+
+        assert!(rules.find("example.com").is_none());
+    }
+}
@@ -0,0 +1,218 @@
+use ::alloc::borrow::Cow;
+use ::alloc::vec::Vec;
+use ::idna::domain_to_unicode;
+
+mod rules {
+    include!(concat!(env!("OUT_DIR"), "/public_suffix_rules.rs"));
+}
+
+/// Returns the byte index within the domain where its public suffix starts,
+/// using the rules of the Public Suffix List.
+/// i.e. for `mail.example.co.uk` this returns the index of `co.uk`.
+///
+/// Domains with no matching rule use the last label as their public suffix,
+/// as the list describes. Domain literals have no public suffix.
+pub(crate) fn find_public_suffix(domain: &str) -> Option<usize> {
+    if domain.starts_with('[') {
+        return None;
+    }
+
+    let lookup_domain = to_lookup_form(domain);
+    let labels: Vec<&str> = lookup_domain.split('.').collect();
+    let suffix_from = |i: usize| labels[i..].join(".");
+
+    // Exception rules take priority over all others.
+    let exception = (0..labels.len()).find(|&i| contains(rules::EXCEPTION_RULES, &suffix_from(i)));
+    let first_suffix_label = match exception {
+        Some(i) => i + 1,
+        None => {
+            let mut first_suffix_label = labels.len() - 1;
+            for i in 0..labels.len() {
+                let suffix = suffix_from(i);
+                if contains(rules::RULES, &suffix) {
+                    first_suffix_label = first_suffix_label.min(i);
+                }
+                if i > 0 && contains(rules::WILDCARD_RULES, &suffix) {
+                    first_suffix_label = first_suffix_label.min(i - 1);
+                }
+            }
+
+            first_suffix_label
+        }
+    };
+
+    // The original domain has the same labels as the lookup form,
+    // so the suffix is found by counting labels from the end.
+    let num_suffix_labels = labels.len() - first_suffix_label;
+    domain
+        .rmatch_indices('.')
+        .nth(num_suffix_labels - 1)
+        .map(|(index, _)| index + 1)
+        .or(Some(0))
+}
+
+/// Returns the byte index within the domain where its registrable domain starts.
+/// This is the public suffix, with the label before it.
+/// i.e. for `mail.example.co.uk` this returns the index of `example.co.uk`.
+///
+/// Returns None if the domain is itself a public suffix.
+pub(crate) fn find_registrable_domain(domain: &str) -> Option<usize> {
+    let suffix_start = find_public_suffix(domain)?;
+    if suffix_start == 0 {
+        return None;
+    }
+
+    let registrable_start = domain[..suffix_start - 1]
+        .rfind('.')
+        .map_or(0, |index| index + 1);
+
+    Some(registrable_start)
+}
+
+/// Returns true if the top level domain given is in the Public Suffix List.
+pub(crate) fn is_known_tld(tld: &str) -> bool {
+    let tld = to_lookup_form(tld);
+
+    contains(rules::RULES, &tld) || contains(rules::WILDCARD_RULES, &tld)
+}
+
+/// The list holds lowercase Unicode rules,
+/// so ASCII encoded international domains are converted before searching.
+fn to_lookup_form(domain: &str) -> Cow<'_, str> {
+    let has_punycode = domain.split('.').any(|label| {
+        label
+            .get(..4)
+            .is_some_and(|prefix| prefix.eq_ignore_ascii_case("xn--"))
+    });
+
+    if has_punycode {
+        let (unicode_domain, result) = domain_to_unicode(domain);
+        if result.is_ok() {
+            return Cow::Owned(unicode_domain);
+        }
+    }
+
+    if domain.chars().any(char::is_uppercase) {
+        Cow::Owned(domain.to_lowercase())
+    } else {
+        Cow::Borrowed(domain)
+    }
+}
+
+fn contains(rules: &[&str], suffix: &str) -> bool {
+    rules.binary_search(&suffix).is_ok()
+}
+
+#[cfg(test)]
+mod test_find_public_suffix {
+    use super::*;
+
+    fn public_suffix(domain: &str) -> Option<&str> {
+        find_public_suffix(domain).map(|index| &domain[index..])
+    }
+
+    #[test]
+    fn it_should_find_simple_suffixes() {
+        assert_eq!(public_suffix("example.com"), Some("com"));
+        assert_eq!(public_suffix("mail.example.com"), Some("com"));
+    }
+
+    #[test]
+    fn it_should_find_the_longest_suffix() {
+        assert_eq!(public_suffix("mail.corp.example.co.uk"), Some("co.uk"));
+    }
+
+    #[test]
+    fn it_should_find_wildcard_suffixes() {
+        assert_eq!(public_suffix("shop.example.ck"), Some("example.ck"));
+    }
+
+    #[test]
+    fn it_should_find_exception_suffixes() {
+        assert_eq!(public_suffix("www.ck"), Some("ck"));
+        assert_eq!(public_suffix("mail.www.ck"), Some("ck"));
+    }
+
+    #[test]
+    fn it_should_use_the_last_label_for_unknown_suffixes() {
+        assert_eq!(public_suffix("example.unknowntld"), Some("unknowntld"));
+        assert_eq!(public_suffix("localhost"), Some("localhost"));
+    }
+
+    #[test]
+    fn it_should_ignore_case() {
+        assert_eq!(public_suffix("Mail.Example.CO.UK"), Some("CO.UK"));
+    }
+
+    #[test]
+    fn it_should_find_international_suffixes() {
+        assert_eq!(public_suffix("example.公司.cn"), Some("公司.cn"));
+        assert_eq!(
+            public_suffix("example.xn--55qx5d.cn"),
+            Some("xn--55qx5d.cn")
+        );
+    }
+
+    #[test]
+    fn it_should_not_find_suffixes_for_domain_literals() {
+        assert_eq!(public_suffix("[192.168.0.1]"), None);
+    }
+}
+
+#[cfg(test)]
+mod test_find_registrable_domain {
+    use super::*;
+
+    fn registrable_domain(domain: &str) -> Option<&str> {
+        find_registrable_domain(domain).map(|index| &domain[index..])
+    }
+
+    #[test]
+    fn it_should_find_the_registrable_domain() {
+        assert_eq!(
+            registrable_domain("mail.corp.example.co.uk"),
+            Some("example.co.uk")
+        );
+        assert_eq!(registrable_domain("example.com"), Some("example.com"));
+        assert_eq!(
+            registrable_domain("shop.example.ck"),
+            Some("shop.example.ck")
+        );
+        assert_eq!(registrable_domain("mail.www.ck"), Some("www.ck"));
+    }
+
+    #[test]
+    fn it_should_use_private_domains() {
+        assert_eq!(
+            registrable_domain("mail.jane.github.io"),
+            Some("jane.github.io")
+        );
+        assert_eq!(registrable_domain("github.io"), None);
+    }
+
+    #[test]
+    fn it_should_return_none_for_public_suffixes() {
+        assert_eq!(registrable_domain("co.uk"), None);
+        assert_eq!(registrable_domain("com"), None);
+        assert_eq!(registrable_domain("example.ck"), None);
+    }
+}
+
+#[cfg(test)]
+mod test_is_known_tld {
+    use super::*;
+
+    #[test]
+    fn it_should_know_tlds_in_the_list() {
+        assert!(is_known_tld("com"));
+        assert!(is_known_tld("UK"));
+        assert!(is_known_tld("ck"));
+        assert!(is_known_tld("xn--55qx5d"));
+    }
+
+    #[test]
+    fn it_should_not_know_other_tlds() {
+        assert!(!is_known_tld("unknowntld"));
+        assert!(!is_known_tld("localhost"));
+    }
+}
@@ -0,0 +1,62 @@
+/// Splits a local part into the mailbox and the subaddress,
+/// at the first occurrence of the separator.
+/// i.e. `john+news` becomes `john` and `news`.
+///
+/// Dots between the mailbox and separator are not part of the mailbox,
+/// so `john.+news` also becomes `john` and `news`.
+///
+/// Returns None if there is no separator, if the local part is quoted,
+/// or if there would be no mailbox left.
+pub(crate) fn split_subaddress(local_part: &str, separator: char) -> Option<(&str, &str)> {
+    if local_part.starts_with('"') {
+        return None;
+    }
+
+    let (mailbox, subaddress) = local_part.split_once(separator)?;
+    let mailbox = mailbox.trim_end_matches('.');
+    if mailbox.is_empty() {
+        return None;
+    }
+
+    Some((mailbox, subaddress))
+}
+
+#[cfg(test)]
+mod test_split_subaddress {
+    use super::*;
+
+    #[test]
+    fn it_should_split_at_the_separator() {
+        assert_eq!(split_subaddress("john+news", '+'), Some(("john", "news")));
+    }
+
+    #[test]
+    fn it_should_split_at_the_first_separator() {
+        assert_eq!(split_subaddress("john-a-b", '-'), Some(("john", "a-b")));
+    }
+
+    #[test]
+    fn it_should_allow_empty_subaddresses() {
+        assert_eq!(split_subaddress("john+", '+'), Some(("john", "")));
+    }
+
+    #[test]
+    fn it_should_remove_trailing_dots_from_the_mailbox() {
+        assert_eq!(split_subaddress("john.+news", '+'), Some(("john", "news")));
+    }
+
+    #[test]
+    fn it_should_not_split_without_a_separator() {
+        assert_eq!(split_subaddress("john", '+'), None);
+    }
+
+    #[test]
+    fn it_should_not_split_without_a_mailbox() {
+        assert_eq!(split_subaddress("+news", '+'), None);
+    }
+
+    #[test]
+    fn it_should_not_split_quoted_local_parts() {
+        assert_eq!(split_subaddress(r#""john+news""#, '+'), None);
+    }
+}
@@ -0,0 +1,33 @@
+use ::core::convert::AsRef;
+
+use crate::EmailError;
+use crate::ValidationPolicy;
+
+/// Validates the given string as an email,
+/// returning why it is invalid if it is not.
+///
+/// This uses the same rules as `is_valid_email`.
+///
+/// ```rust
+/// use ::serde_email::validate_email;
+/// use ::serde_email::EmailErrorKind;
+///
+/// let err = validate_email("john doe@example.com").unwrap_err();
+/// assert_eq!(err.kind(), &EmailErrorKind::InvalidCharacter { character: ' ', position: 4 });
+/// ```
+pub fn validate_email<S>(raw: S) -> Result<(), EmailError>
+where
+    S: AsRef<str>,
+{
+    ValidationPolicy::new().validate(raw)
+}
+
+/// Validates the given string as an email,
+/// using the rules of the `ValidationPolicy` or `ValidationMode` given.
+pub fn validate_email_with<S, P>(raw: S, policy: P) -> Result<(), EmailError>
+where
+    S: AsRef<str>,
+    P: Into<ValidationPolicy>,
+{
+    policy.into().validate(raw)
+}
@@ -0,0 +1,239 @@
+use crate::Grammar;
+use crate::ValidationPolicy;
+
+/// Ready made policies, for validating emails to match other systems.
+///
+/// A mode can be given anywhere a `ValidationPolicy` is taken,
+/// such as `is_valid_email_with` and `Email::from_str_with`.
+/// It can also be turned into a policy with `ValidationMode::policy`,
+/// to change its rules further.
+///
+/// ```rust
+/// use ::serde_email::is_valid_email_with;
+/// use ::serde_email::Email;
+/// use ::serde_email::ValidationMode;
+///
+/// assert!(is_valid_email_with("john..doe@example.com", ValidationMode::Html5));
+/// assert!(Email::from_str_with("john..doe@example.com", ValidationMode::Html5).is_ok());
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ValidationMode {
+    /// The rules used by `is_valid_email` and `Email::from_str`.
+    #[default]
+    Default,
+
+    /// Matches `<input type="email">` in browsers, using the regex from the WHATWG HTML spec.
+    ///
+    /// An email accepted by the browser will be accepted by this, and the same for those it rejects.
+    /// Note browsers trim whitespace from the value before testing it.
+    ///
+    /// This differs from the default in a number of ways.
+    /// Dots can appear anywhere in the local part, and there is no limit on the lengths.
+    /// Quoted local parts, domain literals, and non-ASCII characters are all rejected.
+    Html5,
+}
+
+impl ValidationMode {
+    /// Returns the policy for this mode.
+    pub const fn policy(self) -> ValidationPolicy {
+        match self {
+            ValidationMode::Default => ValidationPolicy::new(),
+            ValidationMode::Html5 => ValidationPolicy::new()
+                .grammar(Grammar::Html5)
+                .allow_utf8(false)
+                .max_local_part_length(usize::MAX)
+                .max_domain_length(usize::MAX)
+                .max_length(usize::MAX),
+        }
+    }
+}
+
+impl From<ValidationMode> for ValidationPolicy {
+    fn from(mode: ValidationMode) -> Self {
+        mode.policy()
+    }
+}
+
+#[cfg(test)]
+mod test_html5 {
+    use super::*;
+
+    /// A direct translation of the regex from the WHATWG HTML spec.
+    ///
+    /// `^[a-zA-Z0-9.!#$%&'*+/=?^_`{|}~-]+@[a-zA-Z0-9](?:[a-zA-Z0-9-]{0,61}[a-zA-Z0-9])?(?:\.[a-zA-Z0-9](?:[a-zA-Z0-9-]{0,61}[a-zA-Z0-9])?)*$`
+    fn whatwg_regex_matches(raw: &str) -> bool {
+        let Some((local_part, domain)) = raw.split_once('@') else {
+            return false;
+        };
+
+        let is_local_char =
+            |c: char| c.is_ascii_alphanumeric() || ".!#$%&'*+/=?^_`{|}~-".contains(c);
+        let is_label = |label: &str| {
+            let bytes = label.as_bytes();
+            (1..=63).contains(&bytes.len())
+                && bytes[0].is_ascii_alphanumeric()
+                && bytes[bytes.len() - 1].is_ascii_alphanumeric()
+                && bytes
+                    .iter()
+                    .all(|&b| b.is_ascii_alphanumeric() || b == b'-')
+        };
+
+        !local_part.is_empty()
+            && local_part.chars().all(is_local_char)
+            && domain.split('.').all(is_label)
+    }
+
+    fn is_valid(raw: &str) -> bool {
+        ValidationMode::Html5.policy().is_valid(raw)
+    }
+
+    /// Every string up to 6 characters long, made from characters which are
+    /// interesting to the grammar, is checked against the regex.
+    #[test]
+    fn it_should_match_the_whatwg_regex_exactly() {
+        let alphabet = ['a', '1', '.', '-', '@', '"', '[', ' ', 'ö', '+'];
+        let mut candidates = vec![String::new()];
+
+        for _ in 0..6 {
+            candidates = candidates
+                .iter()
+                .flat_map(|candidate| {
+                    alphabet.iter().map(move |&c| {
+                        let mut next = candidate.clone();
+                        next.push(c);
+                        next
+                    })
+                })
+                .collect();
+
+            for candidate in &candidates {
+                assert_eq!(
+                    is_valid(candidate),
+                    whatwg_regex_matches(candidate),
+                    "{candidate:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn it_should_match_the_whatwg_regex_for_long_labels() {
+        let label_63 = "a".repeat(63);
+        let label_64 = "a".repeat(64);
+
+        for raw in [
+            format!("john@{label_63}.com"),
+            format!("john@{label_64}.com"),
+            format!("john@{label_63}"),
+            format!("{}@example.com", "a".repeat(300)),
+            format!("john@{}", [label_63.as_str(); 10].join(".")),
+        ] {
+            assert_eq!(is_valid(&raw), whatwg_regex_matches(&raw), "{raw:?}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_divergence {
+    use super::*;
+
+    /// Where the default mode and the HTML5 mode disagree.
+    /// Each address is listed with if it is valid in the default mode, and then HTML5.
+    const DIVERGENCES: &[(&str, bool, bool)] = &[
+        // Dots can go anywhere in HTML5.
+        (".john@example.com", false, true),
+        ("john.@example.com", false, true),
+        ("john..doe@example.com", false, true),
+        // HTML5 has no length limits.
+        (
+            "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa@example.com",
+            false,
+            true,
+        ),
+        // HTML5 does not allow quoted local parts.
+        (r#""john doe"@example.com"#, true, false),
+        (r#""john@home"@example.com"#, true, false),
+        // HTML5 does not allow domain literals.
+        ("john@[192.168.0.1]", true, false),
+        ("john@[IPv6:2001:db8::1]", true, false),
+        // HTML5 is ASCII only. Browsers convert internationalised domains before validating.
+        ("jöhn@example.com", true, false),
+        ("john@bücher.de", true, false),
+    ];
+
+    #[test]
+    fn it_should_diverge_from_the_default_mode() {
+        let default = ValidationMode::Default.policy();
+        let html5 = ValidationMode::Html5.policy();
+
+        for &(raw, is_valid_by_default, is_valid_in_html5) in DIVERGENCES {
+            assert_eq!(
+                default.is_valid(raw),
+                is_valid_by_default,
+                "default {raw:?}"
+            );
+            assert_eq!(html5.is_valid(raw), is_valid_in_html5, "html5 {raw:?}");
+        }
+    }
+
+    #[test]
+    fn it_should_agree_on_common_emails() {
+        let default = ValidationMode::Default.policy();
+        let html5 = ValidationMode::Html5.policy();
+
+        for raw in [
+            "john@example.com",
+            "john.doe+news@mail.example.co.uk",
+            "john@localhost",
+            "john@123.com",
+            "john@-example.com",
+            "john@example..com",
+            "john doe@example.com",
+            "john@",
+            "@example.com",
+        ] {
+            assert_eq!(default.is_valid(raw), html5.is_valid(raw), "{raw:?}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_policy {
+    use super::*;
+
+    #[test]
+    fn it_should_use_the_default_policy_by_default() {
+        assert_eq!(ValidationMode::default().policy(), ValidationPolicy::new());
+        assert_eq!(
+            ValidationPolicy::from(ValidationMode::Default),
+            ValidationPolicy::new()
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_entry_points {
+    use super::*;
+    use crate::is_valid_email_with;
+    use crate::validate_email_with;
+    use crate::Email;
+    use crate::EmailRef;
+
+    #[test]
+    fn it_should_be_accepted_when_validating() {
+        let raw = format!("{}@example.com", "a".repeat(100));
+
+        assert!(is_valid_email_with(&raw, ValidationMode::Html5));
+        assert!(validate_email_with(&raw, ValidationMode::Html5).is_ok());
+        assert!(!is_valid_email_with(&raw, ValidationMode::Default));
+    }
+
+    #[test]
+    fn it_should_be_accepted_when_building_emails() {
+        let raw = ".john@example.com";
+
+        assert!(Email::from_str_with(raw, ValidationMode::Html5).is_ok());
+        assert!(EmailRef::from_str_with(raw, ValidationMode::Html5).is_ok());
+        assert!(Email::from_str_with(raw, ValidationMode::Default).is_err());
+    }
+}
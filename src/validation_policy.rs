@@ -0,0 +1,581 @@
+use ::alloc::string::ToString;
+use ::core::convert::AsRef;
+
+use crate::parse_email::parse_email;
+use crate::EmailError;
+use crate::Grammar;
+
+/// The rules used when validating an email address.
+///
+/// The default policy is the one used by `is_valid_email` and `Email::from_str`.
+/// Policies are built up by chaining the setters, and then given to
+/// `Email::from_str_with` or `Email::from_string_with`.
+///
+/// ```rust
+/// use ::serde_email::Email;
+/// use ::serde_email::ValidationPolicy;
+///
+/// let policy = ValidationPolicy::new()
+///     .allow_domain_literals(false)
+///     .require_tld(true);
+///
+/// assert!(Email::from_str_with("john@example.com", &policy).is_ok());
+/// assert!(Email::from_str_with("john@localhost", &policy).is_err());
+/// assert!(Email::from_str_with("john@[192.168.0.1]", &policy).is_err());
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ValidationPolicy {
+    pub(crate) grammar: Grammar,
+    pub(crate) allow_domain_literals: bool,
+    pub(crate) allow_private_domain_literals: bool,
+    pub(crate) allow_quoted_local_parts: bool,
+    pub(crate) require_tld: bool,
+    #[cfg(feature = "public-suffix")]
+    pub(crate) require_known_tld: bool,
+    #[cfg(feature = "disposable")]
+    pub(crate) reject_disposable_domains: bool,
+    pub(crate) allow_single_label_domains: bool,
+    pub(crate) max_local_part_length: usize,
+    pub(crate) max_domain_length: usize,
+    pub(crate) max_length: usize,
+    pub(crate) allow_utf8: bool,
+    pub(crate) allow_utf8_local_part: bool,
+}
+
+impl ValidationPolicy {
+    /// Creates the default policy.
+    ///
+    /// This uses the RFC 5322 grammar,
+    /// and allows domain literals, quoted local parts, single label domains, and UTF-8.
+    /// As per RFC 5321, the local part is limited to 64 bytes, the domain to 255 bytes,
+    /// and the whole address to 254 bytes.
+    pub const fn new() -> Self {
+        Self {
+            grammar: Grammar::Rfc5322,
+            allow_domain_literals: true,
+            allow_private_domain_literals: true,
+            allow_quoted_local_parts: true,
+            require_tld: false,
+            #[cfg(feature = "public-suffix")]
+            require_known_tld: false,
+            #[cfg(feature = "disposable")]
+            reject_disposable_domains: false,
+            allow_single_label_domains: true,
+            max_local_part_length: 64,
+            max_domain_length: 255,
+            max_length: 254,
+            allow_utf8: true,
+            allow_utf8_local_part: true,
+        }
+    }
+
+    /// Sets the grammar the address is parsed with.
+    ///
+    /// The other rules of the policy are checked on top of the grammar.
+    pub const fn grammar(self, grammar: Grammar) -> Self {
+        Self { grammar, ..self }
+    }
+
+    /// Sets if domain literals, such as `john@[192.168.0.1]`, are allowed.
+    pub const fn allow_domain_literals(self, allow: bool) -> Self {
+        Self {
+            allow_domain_literals: allow,
+            ..self
+        }
+    }
+
+    /// Sets if domain literals holding a private IP address,
+    /// such as `john@[192.168.0.1]` or `john@[IPv6:::1]`, are allowed.
+    ///
+    /// See `Domain::is_private` for the addresses counted as private.
+    pub const fn allow_private_domain_literals(self, allow: bool) -> Self {
+        Self {
+            allow_private_domain_literals: allow,
+            ..self
+        }
+    }
+
+    /// Sets if quoted local parts, such as `"john doe"@example.com`, are allowed.
+    pub const fn allow_quoted_local_parts(self, allow: bool) -> Self {
+        Self {
+            allow_quoted_local_parts: allow,
+            ..self
+        }
+    }
+
+    /// Sets if the domain must end with a top level domain, such as `.com`.
+    ///
+    /// When required the domain must have at least two labels,
+    /// and the last label cannot be entirely numeric.
+    pub const fn require_tld(self, require: bool) -> Self {
+        Self {
+            require_tld: require,
+            ..self
+        }
+    }
+
+    /// Sets if the top level domain must be one found in the Public Suffix List.
+    ///
+    /// The list is a snapshot embedded when the crate is built.
+    /// Domain literals are not checked.
+    #[cfg(feature = "public-suffix")]
+    pub const fn require_known_tld(self, require: bool) -> Self {
+        Self {
+            require_known_tld: require,
+            ..self
+        }
+    }
+
+    /// Sets if domains of disposable email services, such as `mailinator.com`, are rejected.
+    ///
+    /// This only uses the built in list, not domains added to a `DisposableDomains`.
+    /// Use `DisposableDomains::check` to also reject domains added at runtime.
+    #[cfg(feature = "disposable")]
+    pub const fn reject_disposable_domains(self, reject: bool) -> Self {
+        Self {
+            reject_disposable_domains: reject,
+            ..self
+        }
+    }
+
+    /// Sets if domains made of a single label, such as `john@localhost`, are allowed.
+    pub const fn allow_single_label_domains(self, allow: bool) -> Self {
+        Self {
+            allow_single_label_domains: allow,
+            ..self
+        }
+    }
+
+    /// Sets the maximum length of the local part, in bytes.
+    pub const fn max_local_part_length(self, max: usize) -> Self {
+        Self {
+            max_local_part_length: max,
+            ..self
+        }
+    }
+
+    /// Sets the maximum length of the domain, in bytes.
+    pub const fn max_domain_length(self, max: usize) -> Self {
+        Self {
+            max_domain_length: max,
+            ..self
+        }
+    }
+
+    /// Sets the maximum length of the whole address, in bytes.
+    pub const fn max_length(self, max: usize) -> Self {
+        Self {
+            max_length: max,
+            ..self
+        }
+    }
+
+    /// Sets if non-ASCII characters are allowed anywhere in the address.
+    pub const fn allow_utf8(self, allow: bool) -> Self {
+        Self {
+            allow_utf8: allow,
+            ..self
+        }
+    }
+
+    /// Sets if non-ASCII characters are allowed in the local part.
+    ///
+    /// Delivering to a non-ASCII local part needs the SMTPUTF8 extension,
+    /// which not all mail systems support. Non-ASCII domains are unaffected,
+    /// as they can be converted to ASCII using `Email::to_ascii_domain`.
+    pub const fn allow_utf8_local_part(self, allow: bool) -> Self {
+        Self {
+            allow_utf8_local_part: allow,
+            ..self
+        }
+    }
+
+    /// Tests if the given string is a valid email under this policy.
+    pub fn is_valid<S>(&self, raw: S) -> bool
+    where
+        S: AsRef<str>,
+    {
+        parse_email(raw.as_ref(), self).is_ok()
+    }
+
+    /// Validates the given string as an email under this policy,
+    /// returning why it is invalid if it is not.
+    pub fn validate<S>(&self, raw: S) -> Result<(), EmailError>
+    where
+        S: AsRef<str>,
+    {
+        let raw_email = raw.as_ref();
+
+        parse_email(raw_email, self)
+            .map(|_| ())
+            .map_err(|kind| EmailError::Invalid {
+                raw_email: raw_email.to_string(),
+                kind,
+            })
+    }
+}
+
+impl Default for ValidationPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Allows a policy to be passed by reference to functions taking `impl Into<ValidationPolicy>`.
+impl From<&ValidationPolicy> for ValidationPolicy {
+    fn from(policy: &ValidationPolicy) -> Self {
+        *policy
+    }
+}
+
+#[cfg(test)]
+mod test_grammar {
+    use super::*;
+    use crate::EmailErrorKind;
+
+    #[test]
+    fn it_should_use_rfc_5322_by_default() {
+        assert_eq!(ValidationPolicy::new().grammar, Grammar::Rfc5322);
+    }
+
+    #[test]
+    fn it_should_parse_with_the_grammar_given() {
+        let policy = ValidationPolicy::new().grammar(Grammar::Html5);
+
+        assert!(policy.is_valid("john..doe@example.com"));
+        assert_eq!(
+            policy.validate(r#""john"@example.com"#).unwrap_err().kind(),
+            &EmailErrorKind::InvalidCharacter {
+                character: '"',
+                position: 0
+            }
+        );
+    }
+
+    #[test]
+    fn it_should_check_the_rest_of_the_policy_on_top_of_the_grammar() {
+        let policy = ValidationPolicy::new()
+            .grammar(Grammar::Html5)
+            .require_tld(true);
+
+        assert!(!policy.is_valid("john@localhost"));
+    }
+}
+
+#[cfg(test)]
+mod test_allow_domain_literals {
+    use super::*;
+    use crate::EmailErrorKind;
+
+    #[test]
+    fn it_should_allow_domain_literals_by_default() {
+        assert!(ValidationPolicy::new().is_valid("john@[192.168.0.1]"));
+    }
+
+    #[test]
+    fn it_should_reject_domain_literals_when_disallowed() {
+        let policy = ValidationPolicy::new().allow_domain_literals(false);
+        let err = policy.validate("john@[192.168.0.1]").unwrap_err();
+
+        assert_eq!(err.kind(), &EmailErrorKind::DomainLiteralNotAllowed);
+    }
+}
+
+#[cfg(test)]
+mod test_allow_private_domain_literals {
+    use super::*;
+    use crate::EmailErrorKind;
+
+    #[test]
+    fn it_should_allow_private_domain_literals_by_default() {
+        assert!(ValidationPolicy::new().is_valid("john@[192.168.0.1]"));
+    }
+
+    #[test]
+    fn it_should_reject_private_domain_literals_when_disallowed() {
+        let policy = ValidationPolicy::new().allow_private_domain_literals(false);
+
+        let err = policy.validate("john@[IPv6:::1]").unwrap_err();
+        assert_eq!(
+            err.kind(),
+            &EmailErrorKind::PrivateDomainLiteral { position: 5 }
+        );
+        assert!(!policy.is_valid("john@[10.0.0.1]"));
+    }
+
+    #[test]
+    fn it_should_allow_public_domain_literals_when_private_are_disallowed() {
+        let policy = ValidationPolicy::new().allow_private_domain_literals(false);
+
+        assert!(policy.is_valid("john@[8.8.8.8]"));
+        assert!(policy.is_valid("john@[tag:content]"));
+        assert!(policy.is_valid("john@example.com"));
+    }
+}
+
+#[cfg(test)]
+mod test_allow_quoted_local_parts {
+    use super::*;
+    use crate::EmailErrorKind;
+
+    #[test]
+    fn it_should_allow_quoted_local_parts_by_default() {
+        assert!(ValidationPolicy::new().is_valid(r#""john doe"@example.com"#));
+    }
+
+    #[test]
+    fn it_should_reject_quoted_local_parts_when_disallowed() {
+        let policy = ValidationPolicy::new().allow_quoted_local_parts(false);
+        let err = policy.validate(r#""john doe"@example.com"#).unwrap_err();
+
+        assert_eq!(err.kind(), &EmailErrorKind::QuotedLocalPartNotAllowed);
+    }
+}
+
+#[cfg(test)]
+mod test_require_tld {
+    use super::*;
+    use crate::EmailErrorKind;
+
+    #[test]
+    fn it_should_not_require_a_tld_by_default() {
+        assert!(ValidationPolicy::new().is_valid("john@localhost"));
+    }
+
+    #[test]
+    fn it_should_accept_domains_with_a_tld() {
+        let policy = ValidationPolicy::new().require_tld(true);
+
+        assert!(policy.is_valid("john@example.com"));
+    }
+
+    #[test]
+    fn it_should_reject_single_label_domains() {
+        let policy = ValidationPolicy::new().require_tld(true);
+        let err = policy.validate("john@localhost").unwrap_err();
+
+        assert_eq!(err.kind(), &EmailErrorKind::MissingTld);
+    }
+
+    #[test]
+    fn it_should_reject_numeric_tlds() {
+        let policy = ValidationPolicy::new().require_tld(true);
+        let err = policy.validate("john@192.168.0.1").unwrap_err();
+
+        assert_eq!(err.kind(), &EmailErrorKind::MissingTld);
+    }
+}
+
+#[cfg(all(test, feature = "public-suffix"))]
+mod test_require_known_tld {
+    use super::*;
+    use crate::EmailErrorKind;
+
+    #[test]
+    fn it_should_not_require_a_known_tld_by_default() {
+        assert!(ValidationPolicy::new().is_valid("john@example.unknowntld"));
+    }
+
+    #[test]
+    fn it_should_accept_known_tlds() {
+        let policy = ValidationPolicy::new().require_known_tld(true);
+
+        assert!(policy.is_valid("john@example.com"));
+        assert!(policy.is_valid("john@example.CO.UK"));
+        assert!(policy.is_valid("john@example.公司"));
+        assert!(policy.is_valid("john@example.xn--55qx5d"));
+    }
+
+    #[test]
+    fn it_should_reject_unknown_tlds() {
+        let policy = ValidationPolicy::new().require_known_tld(true);
+        let err = policy.validate("john@example.unknowntld").unwrap_err();
+
+        assert_eq!(err.kind(), &EmailErrorKind::UnknownTld { position: 13 });
+    }
+
+    #[test]
+    fn it_should_not_check_domain_literals() {
+        let policy = ValidationPolicy::new().require_known_tld(true);
+
+        assert!(policy.is_valid("john@[192.168.0.1]"));
+    }
+}
+
+#[cfg(all(test, feature = "disposable"))]
+mod test_reject_disposable_domains {
+    use super::*;
+    use crate::EmailErrorKind;
+
+    #[test]
+    fn it_should_allow_disposable_domains_by_default() {
+        assert!(ValidationPolicy::new().is_valid("john@mailinator.com"));
+    }
+
+    #[test]
+    fn it_should_reject_disposable_domains() {
+        let policy = ValidationPolicy::new().reject_disposable_domains(true);
+        let err = policy.validate("john@mail.mailinator.com").unwrap_err();
+
+        assert_eq!(
+            err.kind(),
+            &EmailErrorKind::DisposableDomain { position: 5 }
+        );
+    }
+
+    #[test]
+    fn it_should_accept_other_domains() {
+        let policy = ValidationPolicy::new().reject_disposable_domains(true);
+
+        assert!(policy.is_valid("john@example.com"));
+        assert!(policy.is_valid("john@[192.168.0.1]"));
+    }
+}
+
+#[cfg(test)]
+mod test_allow_single_label_domains {
+    use super::*;
+    use crate::EmailErrorKind;
+
+    #[test]
+    fn it_should_reject_single_label_domains_when_disallowed() {
+        let policy = ValidationPolicy::new().allow_single_label_domains(false);
+        let err = policy.validate("john@localhost").unwrap_err();
+
+        assert_eq!(err.kind(), &EmailErrorKind::SingleLabelDomain);
+    }
+
+    #[test]
+    fn it_should_accept_numeric_tlds_when_disallowed() {
+        let policy = ValidationPolicy::new().allow_single_label_domains(false);
+
+        assert!(policy.is_valid("john@192.168.0.1"));
+    }
+}
+
+#[cfg(test)]
+mod test_max_lengths {
+    use super::*;
+    use crate::EmailErrorKind;
+
+    #[test]
+    fn it_should_reject_local_parts_over_the_maximum() {
+        let policy = ValidationPolicy::new().max_local_part_length(4);
+        let err = policy.validate("johnny@example.com").unwrap_err();
+
+        assert_eq!(
+            err.kind(),
+            &EmailErrorKind::LocalPartTooLong { length: 6, max: 4 }
+        );
+    }
+
+    #[test]
+    fn it_should_reject_domains_over_the_maximum() {
+        let policy = ValidationPolicy::new().max_domain_length(8);
+        let err = policy.validate("john@example.com").unwrap_err();
+
+        assert_eq!(
+            err.kind(),
+            &EmailErrorKind::DomainTooLong { length: 11, max: 8 }
+        );
+    }
+
+    #[test]
+    fn it_should_reject_addresses_over_the_maximum() {
+        let policy = ValidationPolicy::new().max_length(10);
+        let err = policy.validate("john@example.com").unwrap_err();
+
+        assert_eq!(
+            err.kind(),
+            &EmailErrorKind::AddressTooLong {
+                length: 16,
+                max: 10
+            }
+        );
+    }
+
+    #[test]
+    fn it_should_limit_addresses_to_254_bytes_by_default() {
+        let domain = vec!["a".repeat(62); 4].join(".");
+
+        assert!(ValidationPolicy::new().is_valid(format!("j@{domain}")));
+        assert!(!ValidationPolicy::new().is_valid(format!("john@{domain}")));
+    }
+}
+
+#[cfg(test)]
+mod test_allow_utf8_local_part {
+    use super::*;
+    use crate::EmailErrorKind;
+
+    #[test]
+    fn it_should_allow_utf8_local_parts_by_default() {
+        let policy = ValidationPolicy::new();
+
+        assert!(policy.is_valid("用户@example.com"));
+        assert!(policy.is_valid("почта@example.com"));
+        assert!(policy.is_valid("🦊@example.com"));
+    }
+
+    #[test]
+    fn it_should_reject_chinese_local_parts_when_disallowed() {
+        let policy = ValidationPolicy::new().allow_utf8_local_part(false);
+        let err = policy.validate("john用户@example.com").unwrap_err();
+
+        assert_eq!(
+            err.kind(),
+            &EmailErrorKind::InvalidCharacter {
+                character: '用',
+                position: 4
+            }
+        );
+    }
+
+    #[test]
+    fn it_should_reject_cyrillic_local_parts_when_disallowed() {
+        let policy = ValidationPolicy::new().allow_utf8_local_part(false);
+
+        assert!(!policy.is_valid("почта@example.com"));
+    }
+
+    #[test]
+    fn it_should_reject_emoji_local_parts_when_disallowed() {
+        let policy = ValidationPolicy::new().allow_utf8_local_part(false);
+
+        assert!(!policy.is_valid("🦊@example.com"));
+        assert!(!policy.is_valid(r#""🦊 fox"@example.com"#));
+    }
+
+    #[test]
+    fn it_should_allow_utf8_domains_when_disallowed() {
+        let policy = ValidationPolicy::new().allow_utf8_local_part(false);
+
+        assert!(policy.is_valid("john@例子.广告"));
+        assert!(policy.is_valid("john@пример.рф"));
+    }
+}
+
+#[cfg(test)]
+mod test_allow_utf8 {
+    use super::*;
+    use crate::EmailErrorKind;
+
+    #[test]
+    fn it_should_allow_utf8_by_default() {
+        assert!(ValidationPolicy::new().is_valid("jöhn@bücher.de"));
+    }
+
+    #[test]
+    fn it_should_reject_utf8_when_disallowed() {
+        let policy = ValidationPolicy::new().allow_utf8(false);
+        let err = policy.validate("john@bücher.de").unwrap_err();
+
+        assert_eq!(
+            err.kind(),
+            &EmailErrorKind::InvalidCharacter {
+                character: 'ü',
+                position: 6
+            }
+        );
+    }
+}
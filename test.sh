@@ -4,5 +4,9 @@ set -e
 
 cargo check
 cargo check --features=all
+cargo check --no-default-features
+cargo check --no-default-features --features=serde
+cargo check --no-default-features --features=public-suffix
+cargo check --no-default-features --features=disposable
 
 cargo test --features=all